@@ -0,0 +1,46 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use game_die::{DieBuilder, DieRng, DieSeededRng, StaticDie};
+use rand::Rng;
+
+/// The original default RNG, which looked up the thread local generator on every roll.
+#[derive(Clone)]
+struct ThreadRngPerRoll {}
+
+impl DieRng for ThreadRngPerRoll {
+    fn random_int(&mut self, l: u16, h: u16) -> u16 {
+        rand::thread_rng().gen_range(l..=h)
+    }
+}
+
+fn roll_many(c: &mut Criterion) {
+    let mut group = c.benchmark_group("roll_many(1_000_000)");
+    group.sample_size(20);
+    group.bench_function("cached DieStdRng", |b| {
+        let mut die = DieBuilder::new().sides(20).build();
+        b.iter(|| black_box(die.roll_many(1_000_000)))
+    });
+    group.bench_function("thread_rng per roll", |b| {
+        let mut die = DieBuilder::new().sides(20).rng(Box::new(ThreadRngPerRoll {})).build();
+        b.iter(|| black_box(die.roll_many(1_000_000)))
+    });
+    group.finish();
+}
+
+fn dispatch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("1_000_000 rolls");
+    group.sample_size(20);
+    group.bench_function("boxed Die", |b| {
+        let mut die = DieBuilder::new().sides(20).rng(Box::new(DieSeededRng::new(1))).build();
+        b.iter(|| (0..1_000_000).map(|_| black_box(die.roll()) as u64).sum::<u64>())
+    });
+    group.bench_function("StaticDie", |b| {
+        let mut die = StaticDie::with_rng(20, DieSeededRng::new(1));
+        b.iter(|| (0..1_000_000).map(|_| black_box(die.roll()) as u64).sum::<u64>())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, roll_many, dispatch);
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+#[cfg(feature = "history")]
+use alloc::vec::Vec;
+use core::future::Future;
+
+/// Asynchronous RNG trait (only if feature "async" is enabled)
+///
+/// The async counterpart of `DieRng`, for randomness which has to be awaited such as a hardware RNG service or a
+/// network oracle. Implementations must return a value in the inclusive range `l..=h`. Implementations may simply use
+/// `async fn random_int`.
+pub trait AsyncDieRng {
+    fn random_int(&mut self, l: u16, h: u16) -> impl Future<Output = u16>;
+}
+
+/// Asynchronous Die (only if feature "async" is enabled)
+///
+/// A uniform Die whose rolls await an `AsyncDieRng`. It is kept separate from the synchronous Die so users without an
+/// async runtime aren't affected. The RNG is stored by value since async trait methods can't be called through a
+/// `dyn` object.
+pub struct AsyncDie<R: AsyncDieRng> {
+    sides: u16,
+    rng: R,
+    total_rolls: u64,
+    #[cfg(feature = "history")]
+    history: Vec<u16>,
+}
+
+impl<R: AsyncDieRng> AsyncDie<R> {
+    /// Creates a new AsyncDie with the given number of sides and RNG. The default number of sides is used if a value
+    /// below 2 is passed.
+    pub fn with_rng(sides: u16, rng: R) -> AsyncDie<R> {
+        Self {
+            sides: if sides > 1 { sides } else { crate::DEFAULT_SIDES },
+            rng,
+            total_rolls: 0,
+            #[cfg(feature = "history")]
+            history: Vec::new(),
+        }
+    }
+
+    /// Gets the number of sides of the Die.
+    pub fn sides(&self) -> u16 {
+        self.sides
+    }
+
+    /// Rolls the Die, awaiting the RNG. The roll is recorded like a roll of the synchronous Die.
+    pub async fn roll(&mut self) -> u16 {
+        let ret = self.rng.random_int(1, self.sides).await;
+        self.total_rolls += 1;
+        #[cfg(feature = "history")]
+        self.history.push(ret);
+        ret
+    }
+
+    /// Gets the total number of times the Die has been rolled.
+    pub fn total_rolls(&self) -> u64 {
+        self.total_rolls
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the roll history list in chronological order (only if feature "history" is enabled)
+    pub fn get_history(&self) -> Vec<u16> {
+        self.history.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockAsyncRng {
+        values: Vec<u16>,
+    }
+
+    impl AsyncDieRng for MockAsyncRng {
+        async fn random_int(&mut self, l: u16, h: u16) -> u16 {
+            tokio::task::yield_now().await;
+            self.values.pop().unwrap_or(l).clamp(l, h)
+        }
+    }
+
+    #[tokio::test]
+    async fn roll_awaits_rng() {
+        let mut die = AsyncDie::with_rng(20, MockAsyncRng { values: vec![25, 7] });
+        assert_eq!(die.roll().await, 7);
+        assert_eq!(die.roll().await, 20);
+        assert_eq!(die.roll().await, 1);
+        assert_eq!(die.total_rolls(), 3);
+        #[cfg(feature = "history")]
+        assert_eq!(die.get_history(), vec![7, 20, 1]);
+    }
+}
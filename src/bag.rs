@@ -0,0 +1,87 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::DieStdRng;
+use crate::{random_inclusive, DieRng, DEFAULT_SIDES};
+
+/// Bag Die
+///
+/// Draws faces without replacement: every face in `1..=sides` is drawn exactly once, in a shuffled order, before the
+/// bag is refilled and reshuffled. This guarantees no repeats within a cycle. The shuffle is driven by a DieRng, so it
+/// can be made deterministic with a custom or seeded RNG.
+pub struct BagDie {
+    sides: u16,
+    bag: Vec<u16>,
+    rng: Box<dyn DieRng>,
+}
+
+impl BagDie {
+    #[cfg(feature = "std")]
+    /// Creates a new BagDie with the given number of sides using a standard RNG (only if feature "std" is enabled).
+    /// The default is used if `sides` is below 2.
+    pub fn new(sides: u16) -> BagDie {
+        Self::with_rng(sides, Box::new(DieStdRng::new()))
+    }
+
+    /// Creates a new BagDie with the given number of sides using the given RNG. The default is used if `sides` is
+    /// below 2.
+    pub fn with_rng(sides: u16, rng: Box<dyn DieRng>) -> BagDie {
+        Self {
+            sides: if sides > 1 { sides } else { DEFAULT_SIDES },
+            bag: Vec::new(),
+            rng,
+        }
+    }
+
+    /// The number of sides of the BagDie.
+    pub fn sides(&self) -> u16 {
+        self.sides
+    }
+
+    /// The number of faces left to draw before the bag is refilled.
+    pub fn remaining(&self) -> usize {
+        self.bag.len()
+    }
+
+    /// Draws the next face from the bag, refilling and reshuffling it first if it's empty.
+    pub fn roll(&mut self) -> u16 {
+        if self.bag.is_empty() {
+            self.refill();
+        }
+        self.bag.pop().expect("bag was just refilled")
+    }
+
+    /// Refills the bag with every face and shuffles it (Fisher-Yates).
+    fn refill(&mut self) {
+        self.bag.extend(1..=self.sides);
+        for i in (1..self.bag.len()).rev() {
+            let j = random_inclusive(self.rng.as_mut(), 0, i as u16) as usize;
+            self.bag.swap(i, j);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::SequenceRng;
+    use alloc::vec;
+
+    #[test]
+    fn every_face_once_per_cycle() {
+        let mut die = BagDie::with_rng(4, Box::new(SequenceRng::new(vec![0, 2, 1])));
+        let mut rolls: Vec<u16> = (0..4).map(|_| die.roll()).collect();
+        assert_eq!(rolls, vec![1, 3, 2, 4]);
+        assert_eq!(die.remaining(), 0);
+        rolls.sort_unstable();
+        assert_eq!(rolls, vec![1, 2, 3, 4]);
+
+        let mut die = BagDie::new(6);
+        for _cycle in 0..3 {
+            let mut rolls: Vec<u16> = (0..6).map(|_| die.roll()).collect();
+            rolls.sort_unstable();
+            assert_eq!(rolls, vec![1, 2, 3, 4, 5, 6]);
+        }
+    }
+}
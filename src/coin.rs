@@ -0,0 +1,118 @@
+use alloc::boxed::Box;
+use alloc::vec;
+#[cfg(feature = "history")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::DieStdRng;
+use crate::{Die, DieBuilder, DieRng};
+
+/// The side a Coin landed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoinSide {
+    /// Heads, face 1 of the underlying d2.
+    Heads,
+    /// Tails, face 2 of the underlying d2.
+    Tails,
+}
+
+impl CoinSide {
+    /// Maps a face of the underlying d2 to a side, 1 being heads and 2 tails.
+    fn from_face(face: u16) -> CoinSide {
+        if face == 1 {
+            CoinSide::Heads
+        } else {
+            CoinSide::Tails
+        }
+    }
+}
+
+/// Coin
+///
+/// A two sided Die with heads/tails semantics. A biased Coin is a loaded d2, so it reuses the Die's weighted faces.
+pub struct Coin {
+    die: Die,
+}
+
+#[cfg(feature = "std")]
+impl Default for Coin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Coin {
+    #[cfg(feature = "std")]
+    /// Creates a new fair Coin using a standard RNG (only if feature "std" is enabled).
+    pub fn new() -> Coin {
+        Self::with_rng(Box::new(DieStdRng::new()))
+    }
+
+    /// Creates a new fair Coin using the given RNG.
+    pub fn with_rng(rng: Box<dyn DieRng>) -> Coin {
+        Self {
+            die: DieBuilder::with_rng(rng).sides(2).build(),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    /// Creates a new Coin landing on heads with probability `p_heads` using a standard RNG (only if feature "std" is
+    /// enabled). See `biased_with_rng`.
+    pub fn biased(p_heads: f64) -> Coin {
+        Self::biased_with_rng(p_heads, Box::new(DieStdRng::new()))
+    }
+
+    /// Creates a new Coin landing on heads with probability `p_heads` using the given RNG. The probability is clamped
+    /// to `0.0..=1.0`, a NaN probability gives a fair Coin.
+    pub fn biased_with_rng(p_heads: f64, rng: Box<dyn DieRng>) -> Coin {
+        let p_heads = p_heads.clamp(0.0, 1.0);
+        Self {
+            die: DieBuilder::with_rng(rng).sides(2).weights(vec![p_heads, 1.0 - p_heads]).build(),
+        }
+    }
+
+    /// Flips the Coin.
+    pub fn flip(&mut self) -> CoinSide {
+        CoinSide::from_face(self.die.roll())
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the history of flips in chronological order (only if feature "history" is enabled)
+    pub fn get_history(&self) -> Vec<CoinSide> {
+        self.die.history().iter().copied().map(CoinSide::from_face).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SequenceRng;
+
+    #[test]
+    fn flip_maps_faces_to_sides() {
+        let mut coin = Coin::with_rng(Box::new(SequenceRng::new(vec![1, 2, 2])));
+        assert_eq!(coin.flip(), CoinSide::Heads);
+        assert_eq!(coin.flip(), CoinSide::Tails);
+        assert_eq!(coin.flip(), CoinSide::Tails);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn biased_coin() {
+        let mut coin = Coin::biased(1.0);
+        assert!((0..100).all(|_| coin.flip() == CoinSide::Heads));
+
+        let mut coin = Coin::biased_with_rng(0.8, Box::new(crate::DieSeededRng::new(11)));
+        let heads = (0..10_000).filter(|_| coin.flip() == CoinSide::Heads).count();
+        assert!((7_500..8_500).contains(&heads), "{} heads", heads);
+    }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn flips_are_recorded() {
+        let mut coin = Coin::with_rng(Box::new(SequenceRng::new(vec![2, 1])));
+        coin.flip();
+        coin.flip();
+        assert_eq!(coin.get_history(), vec![CoinSide::Tails, CoinSide::Heads]);
+    }
+}
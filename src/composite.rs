@@ -0,0 +1,99 @@
+use alloc::boxed::Box;
+#[cfg(feature = "history")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::DieStdRng;
+use crate::{Die, DieBuilder, DieRng};
+
+/// Composite Die
+///
+/// Rolls a single-digit base die several times and reads the results as the digits of one number, as used by d66 or
+/// d666 tables. For a d66 each roll is `tens * 10 + units`, giving values from 11 to 66.
+pub struct CompositeDie {
+    die: Die,
+    digits: u8,
+}
+
+impl CompositeDie {
+    #[cfg(feature = "std")]
+    /// Creates a new CompositeDie reading `digits` rolls of a `base_sides` die using a standard RNG (only if feature
+    /// "std" is enabled).
+    ///
+    /// # Panics
+    /// Panics if `base_sides` is not in `2..=9` or `digits` is not in `1..=9`.
+    pub fn new(base_sides: u16, digits: u8) -> CompositeDie {
+        Self::with_rng(base_sides, digits, Box::new(DieStdRng::new()))
+    }
+
+    /// Creates a new CompositeDie reading `digits` rolls of a `base_sides` die using the given RNG.
+    ///
+    /// # Panics
+    /// Panics if `base_sides` is not in `2..=9` or `digits` is not in `1..=9`.
+    pub fn with_rng(base_sides: u16, digits: u8, rng: Box<dyn DieRng>) -> CompositeDie {
+        assert!((2..=9).contains(&base_sides), "composite die base must have 2 to 9 sides, got {}", base_sides);
+        assert!((1..=9).contains(&digits), "composite die must have 1 to 9 digits, got {}", digits);
+        Self {
+            die: DieBuilder::with_rng(rng).sides(base_sides).build(),
+            digits,
+        }
+    }
+
+    /// The number of rolls combined into each value.
+    pub fn digits(&self) -> u8 {
+        self.digits
+    }
+
+    /// Rolls the base die once per digit, most significant digit first, and returns the combined value.
+    pub fn roll(&mut self) -> u32 {
+        (0..self.digits).fold(0, |value, _| value * 10 + self.die.roll() as u32)
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the history of the individual base die rolls, most significant digit first for each composite roll (only
+    /// if feature "history" is enabled)
+    pub fn get_history(&self) -> Vec<u16> {
+        self.die.get_history()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SequenceRng;
+    use alloc::vec;
+
+    #[test]
+    fn roll_concatenates_digits() {
+        let mut d66 = CompositeDie::with_rng(6, 2, Box::new(SequenceRng::new(vec![3, 5, 6, 1])));
+        assert_eq!(d66.roll(), 35);
+        assert_eq!(d66.roll(), 61);
+        let mut d666 = CompositeDie::with_rng(6, 3, Box::new(SequenceRng::new(vec![1, 4, 6])));
+        assert_eq!(d666.roll(), 146);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_in_range() {
+        let mut die = CompositeDie::new(6, 2);
+        for _i in 0..1000 {
+            let roll = die.roll();
+            assert!((1..=6).contains(&(roll / 10)) && (1..=6).contains(&(roll % 10)));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic]
+    fn rejects_multi_digit_base() {
+        CompositeDie::new(10, 2);
+    }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn roll_records_each_digit() {
+        let mut die = CompositeDie::with_rng(6, 2, Box::new(SequenceRng::new(vec![2, 4])));
+        assert_eq!(die.roll(), 24);
+        assert_eq!(die.get_history(), vec![2, 4]);
+    }
+}
@@ -0,0 +1,74 @@
+use alloc::boxed::Box;
+
+#[cfg(feature = "std")]
+use crate::DieStdRng;
+use crate::{random_inclusive, DieRng};
+
+/// Const Die
+///
+/// A die whose number of sides is fixed at compile time, e.g. `ConstDie::<20>`. Randomness comes from a DieRng just
+/// like a regular Die. A `ConstDie` with fewer than 2 sides fails to compile:
+///
+/// ```compile_fail
+/// let die = game_die::ConstDie::<1>::new();
+/// ```
+pub struct ConstDie<const SIDES: u16> {
+    rng: Box<dyn DieRng>,
+}
+
+impl<const SIDES: u16> ConstDie<SIDES> {
+    const VALID_SIDES: () = assert!(SIDES > 1, "a ConstDie must have at least 2 sides");
+
+    #[cfg(feature = "std")]
+    /// Creates a new ConstDie using a standard RNG (only if feature "std" is enabled).
+    pub fn new() -> ConstDie<SIDES> {
+        Self::with_rng(Box::new(DieStdRng::new()))
+    }
+
+    /// Creates a new ConstDie using the given RNG.
+    pub fn with_rng(rng: Box<dyn DieRng>) -> ConstDie<SIDES> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_SIDES;
+        Self { rng }
+    }
+
+    /// The number of sides of the ConstDie.
+    pub const fn sides(&self) -> u16 {
+        SIDES
+    }
+
+    /// Rolls the ConstDie using it's internal RNG, returning a value in `1..=SIDES`.
+    pub fn roll(&mut self) -> u16 {
+        random_inclusive(self.rng.as_mut(), 1, SIDES)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const SIDES: u16> Default for ConstDie<SIDES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SequenceRng;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_six_sided_const_die() {
+        let mut die = ConstDie::<6>::new();
+        assert_eq!(die.sides(), 6);
+        for _i in 0..100 {
+            assert!((1..=6).contains(&die.roll()));
+        }
+    }
+
+    #[test]
+    fn roll_with_custom_rng() {
+        let mut die = ConstDie::<20>::with_rng(Box::new(SequenceRng::new(alloc::vec![20, 3])));
+        assert_eq!(die.roll(), 20);
+        assert_eq!(die.roll(), 3);
+    }
+}
@@ -0,0 +1,71 @@
+use core::cmp::Ordering;
+
+use crate::{Die, MAX_REROLLS};
+
+/// The winner of an opposed roll, created by `contest`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Contest {
+    /// The first Die rolled higher.
+    AWins,
+    /// The second Die rolled higher.
+    BWins,
+    /// Both dice rolled the same value.
+    Tie,
+}
+
+/// Rolls `a` and `b` once each and compares the results, the higher roll winning. Each roll is recorded by the Die that
+/// made it.
+pub fn contest(a: &mut Die, b: &mut Die) -> Contest {
+    match a.roll().cmp(&b.roll()) {
+        Ordering::Greater => Contest::AWins,
+        Ordering::Less => Contest::BWins,
+        Ordering::Equal => Contest::Tie,
+    }
+}
+
+/// Like `contest`, but rerolls both dice on a tie. Gives up after `MAX_REROLLS` rerolls, e.g. for two constant dice,
+/// and reports a tie. Every roll, including the tied ones, is recorded.
+pub fn contest_no_tie(a: &mut Die, b: &mut Die) -> Contest {
+    let mut outcome = contest(a, b);
+    let mut rerolls = 0;
+    while outcome == Contest::Tie && rerolls < MAX_REROLLS {
+        outcome = contest(a, b);
+        rerolls += 1;
+    }
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DieBuilder, SequenceRng};
+    use alloc::boxed::Box;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn sequence(values: Vec<u16>) -> Die {
+        DieBuilder::with_rng(Box::new(SequenceRng::new(values))).sides(20).build()
+    }
+
+    #[test]
+    fn contest_picks_higher_roll() {
+        let (mut a, mut b) = (sequence(vec![15, 7, 9]), sequence(vec![12, 18, 9]));
+        assert_eq!(contest(&mut a, &mut b), Contest::AWins);
+        assert_eq!(contest(&mut a, &mut b), Contest::BWins);
+        assert_eq!(contest(&mut a, &mut b), Contest::Tie);
+        #[cfg(feature = "history")]
+        assert_eq!((a.history(), b.history()), (&[15, 7, 9][..], &[12, 18, 9][..]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn contest_no_tie_rerolls_ties() {
+        let (mut a, mut b) = (sequence(vec![9, 9, 4]), sequence(vec![9, 9, 11]));
+        assert_eq!(contest_no_tie(&mut a, &mut b), Contest::BWins);
+        assert_eq!(a.total_rolls(), 3);
+
+        let (mut a, mut b) = (DieBuilder::new().constant().build(), DieBuilder::new().constant().build());
+        assert_eq!(contest_no_tie(&mut a, &mut b), Contest::Tie);
+        assert_eq!(b.total_rolls(), MAX_REROLLS as u64 + 1);
+    }
+}
@@ -0,0 +1,86 @@
+use alloc::collections::BTreeMap;
+#[cfg(feature = "history")]
+use alloc::vec::Vec;
+
+use crate::Die;
+
+/// Computes the exact probability of every possible sum of rolling `a` and `b` together, e.g. the familiar triangle
+/// from 2 to 12 for two d6. The distribution is derived from the dice's sides and weights rather than by rolling, so
+/// neither RNG is used. Sums which can't be rolled (e.g. because of zero weights) are absent.
+pub fn sum_distribution(a: &Die, b: &Die) -> BTreeMap<u32, f64> {
+    let mut distribution = BTreeMap::new();
+    let b_faces = b.probabilities();
+    for (a_face, a_p) in a.probabilities() {
+        for (b_face, b_p) in &b_faces {
+            let p = a_p * b_p;
+            if p > 0.0 {
+                *distribution.entry(a_face as u32 + *b_face as u32).or_insert(0.0) += p;
+            }
+        }
+    }
+    distribution
+}
+
+/// Convolves `distribution` with the faces of `die`, giving the distribution of the previous sums plus (or minus, if
+/// `subtract` is set) one roll of `die`. Starting from `{0: 1.0}` this builds the distribution of any signed pool.
+pub(crate) fn convolve(distribution: &BTreeMap<i32, f64>, die: &Die, subtract: bool) -> BTreeMap<i32, f64> {
+    let mut convolved = BTreeMap::new();
+    let faces = die.probabilities();
+    for (sum, sum_p) in distribution {
+        for (face, face_p) in &faces {
+            let p = sum_p * face_p;
+            if p > 0.0 {
+                let face = if subtract { -(*face as i32) } else { *face as i32 };
+                *convolved.entry(sum + face).or_insert(0.0) += p;
+            }
+        }
+    }
+    convolved
+}
+
+#[cfg(feature = "history")]
+/// Concatenates the histories of `dice` in the given order, e.g. to analyse every d20 rolled in a session together
+/// (only if feature "history" is enabled). Dice of different sizes may be mixed, but the merged history is then
+/// meaningless for distribution analysis such as a histogram or chi-square test.
+pub fn merge_histories(dice: &[&Die]) -> Vec<u16> {
+    dice.iter().flat_map(|die| die.history()).copied().collect()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::DieBuilder;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn two_d6_distribution() {
+        let d6 = DieBuilder::new().sides(6).build();
+        let distribution = sum_distribution(&d6, &d6);
+        assert_eq!(distribution.keys().copied().collect::<Vec<_>>(), (2..=12).collect::<Vec<_>>());
+        assert!((distribution[&7] - 6.0 / 36.0).abs() < 1e-12);
+        assert!((distribution[&2] - 1.0 / 36.0).abs() < 1e-12);
+        assert!((distribution.values().sum::<f64>() - 1.0).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn merge_two_histories() {
+        let a = DieBuilder::new().sides(6).history(vec![1, 4]).build();
+        let b = DieBuilder::new().sides(6).history(vec![6, 2, 3]).build();
+        let merged = merge_histories(&[&a, &b]);
+        assert_eq!(merged, vec![1, 4, 6, 2, 3]);
+        assert_eq!(merged.len(), 5);
+        assert!(merge_histories(&[]).is_empty());
+    }
+
+    #[test]
+    fn weighted_distribution() {
+        let coin = DieBuilder::new().weights(vec![3.0, 1.0, 0.0]).build();
+        let d4 = DieBuilder::new().sides(4).build();
+        let distribution = sum_distribution(&coin, &d4);
+        assert_eq!(distribution.len(), 5);
+        assert!((distribution[&2] - 0.75 / 4.0).abs() < 1e-12);
+        assert!((distribution[&6] - 0.25 / 4.0).abs() < 1e-12);
+    }
+}
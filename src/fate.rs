@@ -0,0 +1,75 @@
+use alloc::boxed::Box;
+
+#[cfg(feature = "std")]
+use crate::DieStdRng;
+use crate::{random_inclusive, DieRng};
+
+/// Fate/Fudge Die (dF)
+///
+/// A die with faces of -1, 0 and +1, each rolled with equal probability. The randomness comes from a DieRng, so custom
+/// or seeded RNGs can be used just like with a regular Die.
+pub struct FateDie {
+    rng: Box<dyn DieRng>,
+}
+
+#[cfg(feature = "std")]
+impl Default for FateDie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FateDie {
+    #[cfg(feature = "std")]
+    /// Creates a new FateDie using a standard RNG (only if feature "std" is enabled).
+    pub fn new() -> FateDie {
+        Self::with_rng(Box::new(DieStdRng::new()))
+    }
+
+    /// Creates a new FateDie using the given RNG.
+    pub fn with_rng(rng: Box<dyn DieRng>) -> FateDie {
+        Self { rng }
+    }
+
+    /// Rolls the FateDie, returning -1, 0 or +1.
+    pub fn roll(&mut self) -> i8 {
+        random_inclusive(self.rng.as_mut(), 1, 3) as i8 - 2
+    }
+
+    /// Rolls `count` FateDice and sums the results, e.g. the standard 4dF ranges from -4 to +4. The sum saturates at
+    /// the bounds of `i8`.
+    pub fn roll_pool(&mut self, count: usize) -> i8 {
+        (0..count).fold(0i8, |total, _| total.saturating_add(self.roll()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct _DieMaxRng {}
+    impl DieRng for _DieMaxRng {
+        fn random_int(&mut self, _l: u16, h: u16) -> u16 {
+            h
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_in_range() {
+        let mut die = FateDie::new();
+        for _i in 0..100 {
+            assert!((-1..=1).contains(&die.roll()));
+            assert!((-4..=4).contains(&die.roll_pool(4)));
+        }
+    }
+
+    #[test]
+    fn roll_with_custom_rng() {
+        let mut die = FateDie::with_rng(Box::new(_DieMaxRng{}));
+        assert_eq!(die.roll(), 1);
+        assert_eq!(die.roll_pool(4), 4);
+        assert_eq!(die.roll_pool(0), 0);
+    }
+}
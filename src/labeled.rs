@@ -0,0 +1,118 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::DieStdRng;
+use crate::{random_inclusive, DieRng, DieSeededRng};
+
+/// Labeled Die
+///
+/// A die whose faces are arbitrary values (strings, enums, etc.) rather than integers, e.g. a direction die reading
+/// North/South/East/West. Each face is rolled with equal probability using a DieRng.
+pub struct LabeledDie<T: Clone> {
+    faces: Vec<T>,
+    rng: Box<dyn DieRng>,
+}
+
+impl<T: Clone> LabeledDie<T> {
+    #[cfg(feature = "std")]
+    /// Retrieve a new instance of the Builder class for a LabeledDie with the given faces (only if feature "std" is
+    /// enabled).
+    pub fn builder(faces: Vec<T>) -> LabeledDieBuilder<T> {
+        LabeledDieBuilder::new(faces)
+    }
+
+    /// The faces of the LabeledDie.
+    pub fn faces(&self) -> &[T] {
+        &self.faces
+    }
+
+    /// Rolls the LabeledDie using it's internal RNG, returning a copy of the face rolled.
+    pub fn roll(&mut self) -> T {
+        let index = match self.faces.len() {
+            1 => 0,
+            len => random_inclusive(self.rng.as_mut(), 0, (len - 1) as u16) as usize,
+        };
+        self.faces[index].clone()
+    }
+}
+
+/// Labeled Die Builder
+///
+/// This class is used to build a new LabeledDie. The user has the option of setting the RNG the die will use.
+pub struct LabeledDieBuilder<T: Clone> {
+    faces: Vec<T>,
+    rng: Box<dyn DieRng>,
+}
+
+impl<T: Clone> LabeledDieBuilder<T> {
+    #[cfg(feature = "std")]
+    /// Creates a new LabeledDieBuilder for the given faces, using a standard RNG (only if feature "std" is enabled).
+    pub fn new(faces: Vec<T>) -> LabeledDieBuilder<T> {
+        Self::with_rng(faces, Box::new(DieStdRng::new()))
+    }
+
+    /// Creates a new LabeledDieBuilder for the given faces, using the given RNG.
+    pub fn with_rng(faces: Vec<T>, rng: Box<dyn DieRng>) -> LabeledDieBuilder<T> {
+        Self { faces, rng }
+    }
+
+    /// Set the desired RNG for the LabeledDie.
+    pub fn rng(mut self, rng: Box<dyn DieRng>) -> LabeledDieBuilder<T> {
+        self.rng = rng;
+        self
+    }
+
+    /// Set a seeded RNG for the LabeledDie, making its rolls reproducible.
+    pub fn seed(self, seed: u64) -> LabeledDieBuilder<T> {
+        self.rng(Box::new(DieSeededRng::new(seed)))
+    }
+
+    /// Build the LabeledDie object with the current Builder parameters. Returns `None` if there are no faces, or more
+    /// than `u16::MAX + 1` faces.
+    pub fn build(self) -> Option<LabeledDie<T>> {
+        if self.faces.is_empty() || self.faces.len() > u16::MAX as usize + 1 {
+            return None;
+        }
+        Some(LabeledDie {
+            faces: self.faces,
+            rng: self.rng,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct _DieMaxRng {}
+    impl DieRng for _DieMaxRng {
+        fn random_int(&mut self, _l: u16, h: u16) -> u16 {
+            h
+        }
+    }
+
+    #[test]
+    fn roll_direction_die() {
+        let faces = vec!["North", "South", "East", "West"];
+        let mut die = LabeledDie::builder(faces.clone()).rng(Box::new(_DieMaxRng{})).build().unwrap();
+        assert_eq!(die.roll(), "West");
+
+        let mut die = LabeledDie::builder(faces.clone()).build().unwrap();
+        for _i in 0..100 {
+            assert!(faces.contains(&die.roll()));
+        }
+    }
+
+    #[test]
+    fn single_face_always_rolls_it() {
+        let mut die = LabeledDie::builder(vec!['x']).build().unwrap();
+        assert_eq!(die.roll(), 'x');
+    }
+
+    #[test]
+    fn empty_faces_are_rejected() {
+        assert!(LabeledDie::<&str>::builder(Vec::new()).build().is_none());
+    }
+}
@@ -1,137 +1,3330 @@
 /*!
 # Features
+* **std** -
+  Enables the default RNG used by `DieBuilder::new`. Without it the crate is `no_std` (requiring only `alloc`) and an
+  RNG must be supplied via `DieBuilder::with_rng`. This is enabled by default.
 * **history** -
   Enables the ability to retrieve the history of rolls for the Die. This may be useful for statistics, etc. This is **NOT** enabled by default.
+* **stats** -
+  Keeps a running mean and variance of every roll (see `RunningStats`) in O(1) memory, independent of **history**. This
+  is **NOT** enabled by default.
+* **serde** -
+  Implements `Serialize`/`Deserialize` for the Die, capturing the number of sides, the face weights and (with
+  **history**) the roll history. The RNG is not serialized, a deserialized Die always uses the default RNG, so this
+  feature requires **std**. This is **NOT** enabled by default.
+* **rayon** -
+  Enables `simulate_parallel` and its reproducible counterpart `simulate_parallel_seeded`, which roll a large number of
+  dice across threads using rayon. Requires **std**. This is **NOT** enabled by default.
+* **summary_history** -
+  Counts how often each face was rolled in O(sides) memory, see `Die::face_counts`. Unlike **history** the order of
+  the rolls isn't kept, but the memory use doesn't grow with the number of rolls. The two can be combined. This is
+  **NOT** enabled by default.
+* **timed_history** -
+  Also records when every roll was made, see `Die::timed_history`. Requires **history** and **std**. The timestamps are
+  `std::time::Instant`s, which can't be serialized, so they are not included by **serde**. This is **NOT** enabled by
+  default.
+* **async** -
+  Enables `AsyncDie`, which awaits an `AsyncDieRng` for every roll, e.g. to draw entropy from a hardware RNG service.
+  The synchronous Die is unaffected. This is **NOT** enabled by default.
+
+# no_std
+Without the **std** feature a Die is built from a user supplied RNG:
+```
+use game_die::{DieBuilder, DieRng};
+
+#[derive(Clone)]
+struct CountingRng(u16);
+
+impl DieRng for CountingRng {
+    fn random_int(&mut self, l: u16, h: u16) -> u16 {
+        self.0 = self.0 % (h - l + 1) + 1;
+        l + self.0 - 1
+    }
+}
+
+let mut die = DieBuilder::with_rng(Box::new(CountingRng(0))).sides(6).build();
+assert_eq!(die.roll_many(7), [1, 2, 3, 4, 5, 6, 1]);
+```
 */
-use rand::Rng;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+#[cfg(feature = "history")]
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "history")]
+use core::fmt::Write;
+use core::hash::{Hash, Hasher};
+use core::ops::RangeInclusive;
+use rand::distributions::{Distribution, WeightedIndex};
+#[cfg(feature = "std")]
+use rand::rngs::SmallRng;
+#[cfg(feature = "std")]
+use std::sync::mpsc::Sender;
+#[cfg(feature = "timed_history")]
+use std::time::Instant;
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
 
+#[cfg(feature = "async")]
+mod async_die;
+mod bag;
+mod coin;
+mod composite;
+mod const_die;
+mod contest;
+mod distribution;
+mod fate;
+mod labeled;
+#[cfg(feature = "rayon")]
+mod parallel;
+pub mod parse;
+mod percentile;
+mod pool;
+#[cfg(feature = "serde")]
+mod serialize;
+mod static_die;
+#[cfg(feature = "stats")]
+mod stats;
+
+#[cfg(feature = "async")]
+pub use async_die::{AsyncDie, AsyncDieRng};
+pub use bag::BagDie;
+pub use coin::{Coin, CoinSide};
+pub use composite::CompositeDie;
+pub use const_die::ConstDie;
+pub use contest::{contest, contest_no_tie, Contest};
+#[cfg(feature = "history")]
+pub use distribution::merge_histories;
+pub use distribution::sum_distribution;
+pub use fate::FateDie;
+pub use labeled::{LabeledDie, LabeledDieBuilder};
+#[cfg(feature = "rayon")]
+pub use parallel::{simulate_parallel, simulate_parallel_seeded};
+pub use percentile::PercentileDie;
+pub use pool::{roll_all, DicePool, Sign};
+pub use static_die::StaticDie;
+#[cfg(feature = "stats")]
+pub use stats::RunningStats;
+
+
+/// The number of sides a Die has when none (or an invalid number) is configured.
+pub const DEFAULT_SIDES: u16 = 6;
+
+/// The default maximum number of explosions for `Die::roll_exploding`.
+pub const DEFAULT_MAX_EXPLOSIONS: u32 = 100;
+
+/// The maximum number of rerolls `Die::roll_reroll_while` will make before giving up and returning the last roll.
+pub const MAX_REROLLS: u32 = 100;
 
 /// Die Object
 /// 
-/// This object is created using the builder pattern. The user has the option of setting the number of sides and the RNG
-/// used by the Die.
+/// This object is created using the builder pattern. The user has the option of setting the number of sides (or the
+/// per-face weights) and the RNG used by the Die.
 pub struct Die {
-    sides: u8,
+    sides: u16,
     rng: Box<dyn DieRng>,
+    weights: Option<Weights>,
+    faces: Option<Vec<u16>>,
+    max_explosions: u32,
+    zero_indexed: bool,
+    crit_low: u16,
+    total_rolls: u64,
+    on_roll: Option<Box<dyn FnMut(u16) + Send + Sync>>,
+    roll_filter: Option<Box<dyn FnMut(u16) -> Option<u16> + Send + Sync>>,
+    #[cfg(feature = "std")]
+    event_sender: Option<Sender<u16>>,
+    #[cfg(feature = "history")]
+    history: BoundedHistory<u16>,
     #[cfg(feature = "history")]
-    history: Vec<u8>
+    history_capacity: usize,
+    /// How many of the most recent history entries were recorded by rolling rather than loaded with `set_history`.
+    #[cfg(feature = "history")]
+    recorded: usize,
+    #[cfg(feature = "timed_history")]
+    timed_history: BoundedHistory<TimedRoll>,
+    #[cfg(feature = "summary_history")]
+    face_counts: Vec<u64>,
+    #[cfg(feature = "stats")]
+    stats: RunningStats
+}
+
+impl Clone for Die {
+    /// Clones the Die's configuration, history and RNG. The RNG is cloned as it implements `Clone`: a stateful RNG
+    /// such as `DieSeededRng` is duplicated including its current state, so a clone of a seeded Die produces the same
+    /// rolls as the original, whereas the default RNG of `DieBuilder::new` reseeds itself from entropy, so the clone
+    /// rolls independently. Closures can't be cloned, so the clone has no `on_roll` callback or `roll_filter`. An
+    /// `event_sender` is cloned, so the clone's rolls are sent to the same channel.
+    fn clone(&self) -> Self {
+        Die {
+            sides: self.sides,
+            rng: self.rng.clone_box(),
+            weights: self.weights.clone(),
+            faces: self.faces.clone(),
+            max_explosions: self.max_explosions,
+            zero_indexed: self.zero_indexed,
+            crit_low: self.crit_low,
+            total_rolls: self.total_rolls,
+            on_roll: None,
+            roll_filter: None,
+            #[cfg(feature = "std")]
+            event_sender: self.event_sender.clone(),
+            #[cfg(feature = "history")]
+            history: self.history.clone(),
+            #[cfg(feature = "history")]
+            history_capacity: self.history_capacity,
+            #[cfg(feature = "history")]
+            recorded: self.recorded,
+            #[cfg(feature = "timed_history")]
+            timed_history: self.timed_history.clone(),
+            #[cfg(feature = "summary_history")]
+            face_counts: self.face_counts.clone(),
+            #[cfg(feature = "stats")]
+            stats: self.stats
+        }
+    }
+}
+
+impl PartialEq for Die {
+    /// Dice are equal when they roll the same faces (same sides, weights, faces and indexing) and, if feature "history"
+    /// is enabled, have the same recorded history. The RNG can't be compared, so it is ignored, as are the roll counter
+    /// and other settings.
+    fn eq(&self, other: &Self) -> bool {
+        let same_weights = match (&self.weights, &other.weights) {
+            (Some(a), Some(b)) => a.values == b.values,
+            (None, None) => true,
+            _ => false,
+        };
+        #[cfg(feature = "history")]
+        if self.history() != other.history() {
+            return false;
+        }
+        self.sides == other.sides
+            && self.zero_indexed == other.zero_indexed
+            && same_weights
+            && self.faces == other.faces
+    }
+}
+
+/// Valid weights are never NaN, so equality is reflexive.
+impl Eq for Die {}
+
+impl Hash for Die {
+    /// Hashes the same fields `eq` compares, so equal Dice hash equally and can be used as map keys.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sides.hash(state);
+        self.zero_indexed.hash(state);
+        self.weights.is_some().hash(state);
+        if let Some(weights) = &self.weights {
+            // Adding 0.0 turns -0.0 into 0.0, which compare equal but have different bits.
+            weights.values.iter().for_each(|w| (w + 0.0).to_bits().hash(state));
+        }
+        self.faces.hash(state);
+        #[cfg(feature = "history")]
+        self.history().hash(state);
+    }
+}
+
+impl fmt::Debug for Die {
+    /// Formats the Die's configuration. Only the length of the history is shown to keep the output short.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("Die");
+        s.field("sides", &self.sides)
+            .field("rng", &format_args!("<dyn DieRng>"))
+            .field("weights", &self.weights.as_ref().map(|w| &w.values))
+            .field("faces", &self.faces)
+            .field("max_explosions", &self.max_explosions)
+            .field("zero_indexed", &self.zero_indexed)
+            .field("crit_low", &self.crit_low)
+            .field("total_rolls", &self.total_rolls);
+        #[cfg(feature = "history")]
+        s.field("history_len", &self.history().len());
+        s.finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for Die {
+    /// A 6 sided die using a standard RNG, equivalent to `DieBuilder::new().build()`.
+    fn default() -> Self {
+        DieBuilder::new().build()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<u16> for Die {
+    /// A Die with the given number of sides using a standard RNG, e.g. `let d20: Die = 20.into();`. The default number
+    /// of sides is used if a value below 2 is passed.
+    fn from(sides: u16) -> Self {
+        DieBuilder::from(sides).build()
+    }
+}
+
+impl fmt::Display for Die {
+    /// Formats the Die in dice notation, e.g. `d6`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "d{}", self.sides)
+    }
 }
 
 impl Die {
-    /// Retrieve a new instance of the Builder class for the Die.
+    #[cfg(feature = "std")]
+    /// Retrieve a new instance of the Builder class for the Die (only if feature "std" is enabled).
     pub fn builder() -> DieBuilder {
         DieBuilder::new()
     }
 
-    /// Rolls the Die using it's internal RNG
-    pub fn roll(&mut self) -> u8 {
-        let ret = self.rng.random_int(1, self.sides);
+    #[cfg(feature = "std")]
+    /// Creates a uniform d100 using a standard RNG, rolling `1..=100` directly rather than composing two d10s as
+    /// `PercentileDie` does (only if feature "std" is enabled).
+    pub fn percentile() -> Die {
+        DieBuilder::new().sides(100).build()
+    }
+
+    /// Gets the number of sides of the Die. For a loaded Die this is the number of weights.
+    pub fn sides(&self) -> u16 {
+        self.sides
+    }
+
+    /// Replaces the Die's RNG with a freshly seeded `DieSeededRng`, so the following rolls match those of a Die built
+    /// with `DieBuilder::seed(seed)`. Whatever RNG was installed before is discarded. The history and statistics are
+    /// kept.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = Box::new(DieSeededRng::new(seed));
+    }
+
+    #[cfg(feature = "std")]
+    /// Returns the Die to its freshly built state for a repeated experiment (only if feature "std" is enabled). The
+    /// history, statistics and `total_rolls` are cleared, and the RNG is reseeded with `seed` as with `reseed`, or
+    /// replaced by a fresh standard RNG if `None`. The sides, weights and other settings are kept. Without feature
+    /// "std", `reset_seeded` does the same for a seed.
+    pub fn reset(&mut self, seed: Option<u64>) {
+        match seed {
+            Some(seed) => self.reset_seeded(seed),
+            None => {
+                self.rng = Box::new(DieStdRng::new());
+                self.clear_rolls();
+            }
+        }
+    }
+
+    /// Returns the Die to its freshly built state like `reset(Some(seed))`, also available without feature "std".
+    pub fn reset_seeded(&mut self, seed: u64) {
+        self.reseed(seed);
+        self.clear_rolls();
+    }
+
+    /// Clears everything recorded by the Die's rolls, see `reset`.
+    fn clear_rolls(&mut self) {
+        self.total_rolls = 0;
         #[cfg(feature = "history")]
+        self.clear_history();
+        #[cfg(feature = "summary_history")]
+        self.face_counts.iter_mut().for_each(|count| *count = 0);
+        #[cfg(feature = "stats")]
         {
-            self.history.push(ret);
+            self.stats = RunningStats::new();
         }
+    }
+
+    /// Rolls the Die using it's internal RNG
+    pub fn roll(&mut self) -> u16 {
+        let ret = self.sample();
+        let ret = self.filter(ret);
+        self.record(ret);
         ret
     }
 
-    #[cfg(feature = "history")]
-    /// Gets the roll history list (only if feature "history" is enabled)
-    pub fn get_history(&self) -> Vec<u8> {
-        self.history.clone()
+    /// Passes `value` through the `roll_filter`, if any, until it is accepted or `MAX_REROLLS` overrides were made.
+    fn filter(&mut self, mut value: u16) -> u16 {
+        if let Some(roll_filter) = self.roll_filter.as_mut() {
+            for _i in 0..MAX_REROLLS {
+                match roll_filter(value) {
+                    Some(overridden) => value = overridden,
+                    None => break,
+                }
+            }
+        }
+        value
     }
-}
 
-/// Die Builder
-/// 
-/// This class is used to build a new die. The user has the option of setting the sides and RNG the die will use.
-pub struct DieBuilder {
-    sides: u8,
-    rng: Box<dyn DieRng>
-}
+    /// Rolls the Die without recording the result, e.g. to preview a roll in a UI. The value isn't pushed to the
+    /// history, counted in `total_rolls` or passed to the `on_roll` callback.
+    ///
+    /// Peeking still advances the RNG, so a following `roll` will not reproduce the peeked value. This is also true of
+    /// deterministic RNGs such as a seeded one, whose next roll is the one that would have followed the peeked value.
+    pub fn peek(&mut self) -> u16 {
+        self.sample()
+    }
 
-impl DieBuilder {
-    /// Creates a new DieBuilder, which defaults to a 6 sided die using a standard RNG.
-    pub fn new() -> DieBuilder {
-        Self { 
-            sides: 6,
-            rng: Box::new(DieStdRng{})
+    /// Rolls the Die like `roll`, but checks that the RNG returned a face within `range()`. A buggy RNG's out of range
+    /// value is returned as `DieError::RngOutOfRange` instead of being recorded.
+    pub fn roll_checked(&mut self) -> Result<u16, DieError> {
+        let ret = self.try_sample().map_err(|got| DieError::RngOutOfRange { got, sides: self.sides })?;
+        if !self.has_face(ret) {
+            return Err(DieError::RngOutOfRange { got: ret, sides: self.sides });
         }
+        let ret = self.filter(ret);
+        self.record(ret);
+        Ok(ret)
+    }
+
+    /// Draws a face from the Die's distribution without recording it. A buggy RNG's out of range face index falls back
+    /// to the highest face.
+    fn sample(&mut self) -> u16 {
+        self.try_sample().unwrap_or_else(|_| self.max())
     }
 
-    /// Set the desired number of sides for the Die. Default value is used if 0 is passed.
-    pub fn sides(mut self, sides: u8) -> DieBuilder {
-        if sides > 1 {
-            self.sides = sides;
+    /// Draws a face from the Die's distribution without recording it, returning the RNG's value as the error if it
+    /// isn't the index of one of the explicit faces.
+    fn try_sample(&mut self) -> Result<u16, u16> {
+        let (min, max) = (self.min(), self.max());
+        if min == max {
+            return Ok(min);
         }
-        self
+        if let Some(faces) = &self.faces {
+            let index = random_inclusive(self.rng.as_mut(), 0, faces.len() as u16 - 1);
+            return faces.get(index as usize).copied().ok_or(index);
+        }
+        Ok(match &self.weights {
+            Some(weights) => weights.index.sample(&mut DieRngCore(self.rng.as_mut())) as u16 + min,
+            None => random_inclusive(self.rng.as_mut(), min, max),
+        })
     }
 
-    /// Set the desired RNG for the Die.
-    pub fn rng(mut self, rng: Box<dyn DieRng>) -> DieBuilder {
-        self.rng = rng;
-        self
+    /// Rolls the Die `rolls` times and summarizes the results, keeping only the running totals and per-face counts in
+    /// memory so millions of rolls can be simulated cheaply. Simulated rolls are not recorded to the history, the
+    /// statistics or the `on_roll` callback, they only advance the RNG.
+    pub fn simulate(&mut self, rolls: usize) -> SimSummary {
+        let mut summary = SimSummary {
+            count: rolls,
+            sum: 0,
+            mean: None,
+            min: None,
+            max: None,
+            histogram: BTreeMap::new(),
+        };
+        for _i in 0..rolls {
+            let roll = self.sample();
+            summary.sum += roll as u64;
+            summary.min = Some(summary.min.map_or(roll, |min| min.min(roll)));
+            summary.max = Some(summary.max.map_or(roll, |max| max.max(roll)));
+            *summary.histogram.entry(roll).or_insert(0) += 1;
+        }
+        if rolls > 0 {
+            summary.mean = Some(summary.sum as f64 / rolls as f64);
+        }
+        summary
     }
 
-    /// Build the Die object with the current Builder parameters.
-    pub fn build(self) -> Die {
-        Die {
+    /// Rolls the Die and adds `modifier` to the result. With `clamp` the result is kept within `range()`, e.g. a bonus
+    /// can't push a roll past the highest face, otherwise it is only kept from dropping below the lowest face. The
+    /// unmodified roll is what gets recorded, so the history still reflects the Die's distribution.
+    pub fn roll_modified(&mut self, modifier: i32, clamp: bool) -> u16 {
+        let modified = self.roll() as i32 + modifier;
+        let high = if clamp { self.max() } else { u16::MAX };
+        modified.clamp(self.min() as i32, high as i32) as u16
+    }
+
+    /// Rolls the Die, returning a human readable summary prefixed with its notation, e.g. `d20: 17`.
+    pub fn roll_formatted(&mut self) -> String {
+        let roll = self.roll();
+        alloc::format!("{}: {}", self, roll)
+    }
+
+    /// Rolls the Die, returning the face rolled along with whether it was a critical (within `crit_range()`, by default
+    /// only the natural maximum) or natural minimum (fumble).
+    pub fn roll_detailed(&mut self) -> RollResult {
+        let value = self.roll();
+        RollResult {
+            value,
             sides: self.sides,
-            rng: self.rng,
-            #[cfg(feature = "history")]
-            history: Vec::new()
+            is_max: self.crit_range().contains(&value),
+            is_min: value == self.min(),
         }
     }
-}
 
-/// RNG trait defines an interface for a Random Number Generater. A user can implement their own RNG and pass it to
-/// the DieBuilder method. This interface is UNSAFE, one utilizing the interface could potentially pass bad parameters
-/// i.e. l >= h. The Die will NOT exhibit this behavior.
-pub trait DieRng {
-    fn random_int(&self, l: u8, h: u8) -> u8;
-}
+    /// Rolls the Die and classifies the result against `difficulty`, e.g. for a skill check. A critical (see
+    /// `crit_range`) or natural minimum is a critical success or failure regardless of the difficulty, otherwise the
+    /// roll succeeds if it is at least `difficulty`.
+    pub fn check(&mut self, difficulty: u16) -> CheckOutcome {
+        let result = self.roll_detailed();
+        if result.is_max {
+            CheckOutcome::CriticalSuccess
+        } else if result.is_min {
+            CheckOutcome::CriticalFailure
+        } else if result.value >= difficulty {
+            CheckOutcome::Success
+        } else {
+            CheckOutcome::Failure
+        }
+    }
 
-/// An RNG implementation using rand crate functions
-struct DieStdRng {}
+    /// Records a roll, counting it, updating the running statistics (if feature "stats" is enabled), pushing it to the
+    /// history (if feature "history" is enabled) and finally notifying the `on_roll` callback and `event_sender`.
+    fn record(&mut self, value: u16) {
+        self.total_rolls += 1;
+        #[cfg(feature = "stats")]
+        self.stats.push(value as f64);
+        #[cfg(feature = "history")]
+        {
+            self.history.push(value, self.history_capacity);
+            self.recorded = (self.recorded + 1).min(self.history.as_slice().len());
+        }
+        #[cfg(feature = "summary_history")]
+        if let Some(count) = self.face_count_mut(value) {
+            *count += 1;
+        }
+        #[cfg(feature = "timed_history")]
+        {
+            self.timed_history.push(TimedRoll { value, at: Instant::now() }, self.history_capacity);
+        }
+        if let Some(on_roll) = self.on_roll.as_mut() {
+            on_roll(value);
+        }
+        #[cfg(feature = "std")]
+        if let Some(event_sender) = &self.event_sender {
+            // A dropped receiver just means nobody is listening anymore.
+            let _ = event_sender.send(value);
+        }
+    }
 
-impl DieRng for DieStdRng {
-    fn random_int(&self, l: u8, h: u8) -> u8 {
-        rand::thread_rng().gen_range(l..h)
+    #[cfg(feature = "summary_history")]
+    /// The entry of `face_counts` for `face`, or `None` if it isn't one of the Die's faces.
+    fn face_count_mut(&mut self, face: u16) -> Option<&mut u64> {
+        let index = match &self.faces {
+            Some(faces) => faces.binary_search(&face).ok()?,
+            None => face.checked_sub(self.min())? as usize,
+        };
+        self.face_counts.get_mut(index)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Rolls a value in `low..=high` using the Die's RNG, ignoring the configured sides (e.g. to pick a random table
+    /// row). The roll is recorded like any other. If `low == high` that value is returned without using the RNG.
+    ///
+    /// # Panics
+    /// Panics if `low > high`.
+    pub fn roll_range(&mut self, low: u16, high: u16) -> u16 {
+        assert!(low <= high, "roll_range called with low ({}) > high ({})", low, high);
+        let ret = if low == high {
+            low
+        } else {
+            random_inclusive(self.rng.as_mut(), low, high)
+        };
+        self.record(ret);
+        ret
+    }
 
-    struct _DieTerribleRng {}
-    impl DieRng for _DieTerribleRng {
-        fn random_int(&self, l: u8, _h: u8) -> u8 {
-            l
+    /// Rolls the Die `count` times, returning the results in the order they were rolled. Each roll is recorded exactly
+    /// as an individual call to `roll` would be.
+    pub fn roll_many(&mut self, count: usize) -> Vec<u16> {
+        (0..count).map(|_| self.roll()).collect()
+    }
+
+    /// Rolls the Die `count` times like `roll_many`, also tallying the criticals (see `crit_range`), natural minimums
+    /// (fumbles) and the sum of the rolls.
+    pub fn roll_many_detailed(&mut self, count: usize) -> BatchResult {
+        let rolls = self.roll_many(count);
+        BatchResult {
+            crits: rolls.iter().filter(|r| self.crit_range().contains(r)).count(),
+            fumbles: rolls.iter().filter(|r| **r == self.min()).count(),
+            sum: rolls.iter().map(|r| *r as u64).sum(),
+            rolls,
         }
     }
 
-    #[test]
-    fn build_and_roll_six_sided_die() {
-        let mut die = DieBuilder::new().sides(6).build();
-        let result = die.roll();
-        let mut valid_range: std::ops::Range<u8> = 1..6;
-        assert_eq!(valid_range.any(|i| i == result), true);
+    /// Rolls the Die once for every slot of `buf`, overwriting it with the results in the order they were rolled. This
+    /// allows a scratch buffer to be reused instead of allocating a new Vec like `roll_many`. Each roll is recorded.
+    pub fn roll_into(&mut self, buf: &mut [u16]) {
+        for slot in buf {
+            *slot = self.roll();
+        }
     }
 
-    #[test]
-    fn use_custom_rng() {
-        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieTerribleRng{})).build();
-        assert_eq!(die.roll(), 1);
+    /// Rolls the Die, rolling again and adding to the total every time the highest face comes up. The chain of
+    /// explosions is capped at the Die's maximum number of explosions (`DEFAULT_MAX_EXPLOSIONS` unless configured), so
+    /// at most `max_explosions + 1` rolls are made. Every individual roll is recorded.
+    pub fn roll_exploding(&mut self) -> u32 {
+        let mut ret = self.roll();
+        let mut total = ret as u32;
+        let mut explosions = 0;
+        while ret == self.max() && explosions < self.max_explosions {
+            ret = self.roll();
+            total += ret as u32;
+            explosions += 1;
+        }
+        total
+    }
+
+    /// Rolls the Die twice and returns the higher result (advantage). Both rolls are recorded.
+    pub fn roll_advantage(&mut self) -> u16 {
+        let first = self.roll();
+        let second = self.roll();
+        first.max(second)
+    }
+
+    /// Rolls the Die twice and returns the lower result (disadvantage). Both rolls are recorded.
+    pub fn roll_disadvantage(&mut self) -> u16 {
+        let first = self.roll();
+        let second = self.roll();
+        first.min(second)
+    }
+
+    /// Rolls the Die, and if `predicate` matches the result rolls exactly once more, returning the second result
+    /// regardless of its value. Every roll is recorded.
+    pub fn roll_reroll_if<F: Fn(u16) -> bool>(&mut self, predicate: F) -> u16 {
+        let ret = self.roll();
+        if predicate(ret) {
+            return self.roll();
+        }
+        ret
+    }
+
+    /// Rolls the Die, and if the result is strictly below `threshold` rolls exactly once more, returning the second
+    /// result even if it is worse, e.g. Great Weapon Fighting rerolls 1s and 2s with a threshold of 3. Every roll is
+    /// recorded.
+    pub fn roll_reroll_below(&mut self, threshold: u16) -> u16 {
+        self.roll_reroll_if(|roll| roll < threshold)
+    }
+
+    /// Rolls the Die, rerolling for as long as `predicate` matches the result. To avoid looping forever on a predicate
+    /// that always matches, at most `MAX_REROLLS` rerolls are made, after which the last roll is returned. Every roll
+    /// is recorded.
+    pub fn roll_reroll_while<F: Fn(u16) -> bool>(&mut self, predicate: F) -> u16 {
+        let mut ret = self.roll();
+        let mut rerolls = 0;
+        while predicate(ret) && rerolls < MAX_REROLLS {
+            ret = self.roll();
+            rerolls += 1;
+        }
+        ret
+    }
+
+    /// Rolls the Die until `predicate` matches the result, giving up after `max_attempts` rolls. Returns the matching
+    /// roll, or `None` if none matched, along with the number of rolls made. Every roll is recorded.
+    pub fn roll_until<F: Fn(u16) -> bool>(&mut self, predicate: F, max_attempts: usize) -> (Option<u16>, usize) {
+        for attempt in 1..=max_attempts {
+            let ret = self.roll();
+            if predicate(ret) {
+                return (Some(ret), attempt);
+            }
+        }
+        (None, max_attempts)
+    }
+
+    /// Rolls the Die `count` times, returning the index and value of the highest roll, e.g. to highlight which physical
+    /// die won. Ties are resolved to the first occurrence. All rolls are recorded.
+    ///
+    /// # Panics
+    /// Panics if `count` is 0.
+    pub fn roll_best_of(&mut self, count: usize) -> (usize, u16) {
+        assert!(count > 0, "roll_best_of called with a count of 0");
+        let rolls = self.roll_many(count);
+        let mut best = (0, rolls[0]);
+        for (i, roll) in rolls.into_iter().enumerate() {
+            if roll > best.1 {
+                best = (i, roll);
+            }
+        }
+        best
+    }
+
+    /// Rolls the Die `count` times, returning the index and value of the lowest roll. Ties are resolved to the first
+    /// occurrence. All rolls are recorded.
+    ///
+    /// # Panics
+    /// Panics if `count` is 0.
+    pub fn roll_worst_of(&mut self, count: usize) -> (usize, u16) {
+        assert!(count > 0, "roll_worst_of called with a count of 0");
+        let rolls = self.roll_many(count);
+        let mut worst = (0, rolls[0]);
+        for (i, roll) in rolls.into_iter().enumerate() {
+            if roll < worst.1 {
+                worst = (i, roll);
+            }
+        }
+        worst
+    }
+
+    /// Rolls the Die `count` times and keeps the highest `keep` results, ordered from highest to lowest. If `keep` is
+    /// at least `count`, every roll is returned. All rolls are recorded, not just the kept ones. Each roll is drawn
+    /// from the Die's weights, if any, so keeping and dropping work the same on weighted Dice.
+    pub fn roll_keep_highest(&mut self, count: usize, keep: usize) -> Vec<u16> {
+        let mut rolls = self.roll_many(count);
+        rolls.sort_unstable_by(|a, b| b.cmp(a));
+        rolls.truncate(keep);
+        rolls
+    }
+
+    /// Rolls the Die `count` times and keeps the lowest `keep` results, ordered from lowest to highest. If `keep` is
+    /// at least `count`, every roll is returned. All rolls are recorded, not just the kept ones.
+    pub fn roll_keep_lowest(&mut self, count: usize, keep: usize) -> Vec<u16> {
+        let mut rolls = self.roll_many(count);
+        rolls.sort_unstable();
+        rolls.truncate(keep);
+        rolls
+    }
+
+    /// Rolls the Die `count` times, returning the results ordered from lowest to highest. The history records the rolls
+    /// in the order they were made, not sorted.
+    pub fn roll_sorted(&mut self, count: usize) -> Vec<u16> {
+        self.roll_keep_lowest(count, count)
+    }
+
+    /// Rolls the Die `count` times, returning the results ordered from highest to lowest. The history records the
+    /// rolls in the order they were made, not sorted.
+    pub fn roll_sorted_desc(&mut self, count: usize) -> Vec<u16> {
+        self.roll_keep_highest(count, count)
+    }
+
+    /// Returns an iterator which rolls the Die every time it is advanced. Each roll is recorded as with `roll`.
+    ///
+    /// The iterator is infinite, so it must be bounded (e.g. with `take`) before being collected.
+    pub fn rolls(&mut self) -> Rolls<'_> {
+        Rolls { die: self }
+    }
+
+    /// Consumes the Die, returning an iterator which yields exactly `count` rolls, e.g.
+    /// `Die::default().into_rolls(10).collect::<Vec<_>>()`. Unlike `rolls` the iterator is finite and owns the Die.
+    pub fn into_rolls(self, count: usize) -> IntoRolls {
+        IntoRolls { die: self, remaining: count }
+    }
+
+    /// Returns an iterator which rolls the Die with `roll_detailed` every time it is advanced, e.g. to count crits with
+    /// `die.result_iter().take(100).filter(|r| r.is_max).count()`. Each roll is recorded as with `roll`.
+    ///
+    /// The iterator is infinite, so it must be bounded (e.g. with `take`) before being collected.
+    pub fn result_iter(&mut self) -> ResultIter<'_> {
+        ResultIter { die: self }
+    }
+
+    /// The lowest face the Die can roll, 0 for a zero-indexed Die and 1 otherwise, or the lowest of the faces set with
+    /// `DieBuilder::faces`.
+    pub fn min(&self) -> u16 {
+        match &self.faces {
+            Some(faces) => faces[0],
+            None if self.zero_indexed => 0,
+            None => 1,
+        }
+    }
+
+    /// The highest face the Die can roll.
+    pub fn max(&self) -> u16 {
+        match &self.faces {
+            Some(faces) => faces[faces.len() - 1],
+            None => self.sides - 1 + self.min(),
+        }
+    }
+
+    /// The inclusive range of faces the Die can roll, i.e. `min()..=max()`. With faces set by `DieBuilder::faces` the
+    /// range may include values which can't be rolled, see `faces`.
+    pub fn range(&self) -> RangeInclusive<u16> {
+        self.min()..=self.max()
+    }
+
+    /// Every face the Die can roll, in ascending order. This is `range()` unless the faces were set explicitly with
+    /// `DieBuilder::faces`.
+    pub fn faces(&self) -> Vec<u16> {
+        match &self.faces {
+            Some(faces) => faces.clone(),
+            None => self.range().collect(),
+        }
+    }
+
+    /// Whether `face` is one of the Die's faces.
+    fn has_face(&self, face: u16) -> bool {
+        match &self.faces {
+            Some(faces) => faces.binary_search(&face).is_ok(),
+            None => self.range().contains(&face),
+        }
+    }
+
+    /// The inclusive range of faces counted as a critical, i.e. `max()..=max()` unless configured with
+    /// `DieBuilder::crit_range`.
+    pub fn crit_range(&self) -> RangeInclusive<u16> {
+        self.crit_low..=self.max()
+    }
+
+    /// The theoretical expected value of a roll, accounting for the face weights if set.
+    pub fn expected_value(&self) -> f64 {
+        match (&self.weights, &self.faces) {
+            (None, None) => (self.min() as f64 + self.max() as f64) / 2.0,
+            _ => self.probabilities().into_iter().map(|(face, p)| face as f64 * p).sum(),
+        }
+    }
+
+    /// The theoretical probability that a roll is at least `target`, accounting for the face weights if set. Targets
+    /// at or below the lowest face give 1.0, targets above the highest face give 0.0.
+    pub fn probability_at_least(&self, target: u16) -> f64 {
+        match (&self.weights, &self.faces) {
+            (None, None) => {
+                let target = (target as u32).clamp(self.min() as u32, self.max() as u32 + 1);
+                (self.max() as u32 + 1 - target) as f64 / self.sides as f64
+            }
+            _ => self.probabilities().into_iter().filter(|(face, _)| *face >= target).map(|(_, p)| p).sum(),
+        }
+    }
+
+    /// The theoretical probability mass function of the Die, mapping every face in `faces()` to the probability of
+    /// rolling it. A uniform Die gives each face `1 / sides`, a loaded Die its normalized weight.
+    pub fn pmf(&self) -> BTreeMap<u16, f64> {
+        self.probabilities().into_iter().collect()
+    }
+
+    /// The probability of rolling each face, as `(face, probability)` pairs in ascending face order.
+    pub(crate) fn probabilities(&self) -> Vec<(u16, f64)> {
+        match &self.weights {
+            Some(weights) => weights.probabilities(self.min()).collect(),
+            None => self.faces().into_iter().map(|face| (face, 1.0 / self.sides as f64)).collect(),
+        }
+    }
+
+    /// The theoretical variance of a roll, accounting for the face weights if set.
+    pub fn variance(&self) -> f64 {
+        match (&self.weights, &self.faces) {
+            (None, None) => {
+                let sides = self.sides as f64;
+                (sides * sides - 1.0) / 12.0
+            }
+            _ => {
+                let mean = self.expected_value();
+                self.probabilities().into_iter().map(|(face, p)| (face as f64 - mean) * (face as f64 - mean) * p).sum()
+            }
+        }
+    }
+
+    #[cfg(feature = "stats")]
+    /// Gets the running mean of every roll, or `None` if the Die hasn't been rolled (only if feature "stats" is
+    /// enabled)
+    pub fn running_mean(&self) -> Option<f64> {
+        self.stats.mean()
+    }
+
+    #[cfg(feature = "stats")]
+    /// Gets the running sample variance of every roll, or `None` if the Die has been rolled fewer than two times (only
+    /// if feature "stats" is enabled)
+    pub fn running_variance(&self) -> Option<f64> {
+        self.stats.variance()
+    }
+
+    #[cfg(feature = "stats")]
+    /// Gets the number of rolls accumulated by the running statistics (only if feature "stats" is enabled)
+    pub fn count(&self) -> u64 {
+        self.stats.count()
+    }
+
+    #[cfg(feature = "summary_history")]
+    /// Gets how many times each face has been rolled, where index `i` holds the count of `faces()[i]`, i.e. face
+    /// `i + 1` unless the Die is zero-indexed or has explicit faces (only if feature "summary_history" is enabled).
+    /// Rolls of other values, e.g. from `roll_range`, aren't counted, nor are rolls loaded with `set_history`.
+    pub fn face_counts(&self) -> Vec<u64> {
+        self.face_counts.clone()
+    }
+
+    /// Gets the total number of times the Die has been rolled. Unlike the history, this is unaffected by
+    /// `clear_history`, `set_history` and the history capacity, it is only reset by `reset` and decremented by
+    /// `undo_last`.
+    pub fn total_rolls(&self) -> u64 {
+        self.total_rolls
     }
 
     #[cfg(feature = "history")]
-    #[test]
-    fn get_history() {
-        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieTerribleRng{})).build();
-        for _i in 0..2 {
-            die.roll();
+    /// Clears the roll history (only if feature "history" is enabled). The total roll count is unaffected.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+        self.recorded = 0;
+        #[cfg(feature = "timed_history")]
+        self.timed_history.clear();
+    }
+
+    /// Rolls the Die `count` times and drops the lowest `drop` results, returning the rest ordered from highest to
+    /// lowest. If `drop` is at least `count`, nothing is returned. All rolls are recorded, including the dropped ones.
+    pub fn roll_drop_lowest(&mut self, count: usize, drop: usize) -> Vec<u16> {
+        self.roll_keep_highest(count, count.saturating_sub(drop))
+    }
+
+    /// Rolls the Die `count` times and drops the highest `drop` results, returning the rest ordered from lowest to
+    /// highest. If `drop` is at least `count`, nothing is returned. All rolls are recorded, including the dropped ones.
+    pub fn roll_drop_highest(&mut self, count: usize, drop: usize) -> Vec<u16> {
+        self.roll_keep_lowest(count, count.saturating_sub(drop))
+    }
+
+    #[cfg(feature = "history")]
+    /// Replaces the roll history with previously recorded rolls in chronological order, e.g. when restoring a saved
+    /// game (only if feature "history" is enabled). Every roll must be one of `faces()`, otherwise the history is left
+    /// untouched and the first offending roll is returned. If the Die has a history capacity, only the most recent
+    /// rolls are kept. The total roll count, running statistics and face counts are unaffected. The loaded rolls have
+    /// no timestamps, so the timed history (if feature "timed_history" is enabled) is cleared.
+    pub fn set_history(&mut self, mut rolls: Vec<u16>) -> Result<(), DieError> {
+        if let Some(roll) = rolls.iter().find(|roll| !self.has_face(**roll)) {
+            return Err(DieError::InvalidRoll(*roll));
         }
-        let history = die.get_history();
-        assert_eq!(history.len(), 2);
-        assert_eq!(history[0], 1);
+        if self.history_capacity > 0 && rolls.len() > self.history_capacity {
+            rolls.drain(..rolls.len() - self.history_capacity);
+        }
+        self.history.replace(rolls);
+        self.recorded = 0;
+        #[cfg(feature = "timed_history")]
+        self.timed_history.clear();
+        Ok(())
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the roll history list in chronological order (only if feature "history" is enabled). If the Die was built
+    /// with a history capacity, only the most recent rolls are retained. This clones the history, `history` borrows it
+    /// instead.
+    pub fn get_history(&self) -> Vec<u16> {
+        self.history().to_vec()
+    }
+
+    #[cfg(feature = "history")]
+    /// Borrows the roll history in chronological order, avoiding the allocation of `get_history` (only if feature
+    /// "history" is enabled).
+    pub fn history(&self) -> &[u16] {
+        self.history.as_slice()
+    }
+
+    #[cfg(feature = "timed_history")]
+    /// Gets the rolls made by this Die along with when they were made, in chronological order (only if feature
+    /// "timed_history" is enabled). The same history capacity applies as for `get_history`. Rolls loaded with
+    /// `set_history` or through serde have no timestamp and are not included, so this may be shorter than the history.
+    pub fn timed_history(&self) -> &[TimedRoll] {
+        self.timed_history.as_slice()
+    }
+
+    #[cfg(feature = "history")]
+    /// Removes and returns the most recent roll from the history, or `None` if it is empty (only if feature "history"
+    /// is enabled). If the roll was made by this Die it is also removed from `total_rolls`, the running statistics and
+    /// the face counts. Rolls loaded with `set_history` or through serde weren't counted by this Die, so undoing them
+    /// only shortens the history.
+    ///
+    /// The RNG is not rewound, so the next roll will not reproduce the undone value.
+    pub fn undo_last(&mut self) -> Option<u16> {
+        let roll = self.history.pop()?;
+        if self.recorded == 0 {
+            return Some(roll);
+        }
+        self.recorded -= 1;
+        #[cfg(feature = "timed_history")]
+        self.timed_history.pop();
+        #[cfg(feature = "summary_history")]
+        if let Some(count) = self.face_count_mut(roll) {
+            *count = count.saturating_sub(1);
+        }
+        self.total_rolls = self.total_rolls.saturating_sub(1);
+        #[cfg(feature = "stats")]
+        self.stats.remove(roll as f64);
+        Some(roll)
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the number of rolls in the history (only if feature "history" is enabled). With a history capacity this
+    /// may be less than `total_rolls`.
+    pub fn history_len(&self) -> usize {
+        self.history().len()
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the sum of the recorded rolls, or 0 if the Die hasn't been rolled (only if feature "history" is enabled)
+    pub fn history_sum(&self) -> u64 {
+        self.history().iter().map(|r| *r as u64).sum()
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the mean of the recorded rolls, or `None` if the Die hasn't been rolled (only if feature "history" is
+    /// enabled)
+    pub fn history_mean(&self) -> Option<f64> {
+        if self.history().is_empty() {
+            return None;
+        }
+        let sum: f64 = self.history().iter().map(|r| *r as f64).sum();
+        Some(sum / self.history().len() as f64)
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the sample variance of the recorded rolls, i.e. using the N-1 denominator since the history is a sample of
+    /// the Die's distribution, or `None` if fewer than two rolls were recorded (only if feature "history" is enabled).
+    /// Comparing it with `variance` helps to spot a misbehaving RNG.
+    pub fn history_variance(&self) -> Option<f64> {
+        if self.history().len() < 2 {
+            return None;
+        }
+        let mean = self.history_mean()?;
+        let squares: f64 = self.history().iter().map(|r| (*r as f64 - mean) * (*r as f64 - mean)).sum();
+        Some(squares / (self.history().len() - 1) as f64)
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    /// Gets the sample standard deviation of the recorded rolls, the square root of `history_variance` (only if
+    /// features "history" and "std" are enabled)
+    pub fn history_std_dev(&self) -> Option<f64> {
+        self.history_variance().map(f64::sqrt)
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the most frequently recorded roll, ties are broken by the smaller value. Returns `None` if the Die hasn't
+    /// been rolled (only if feature "history" is enabled)
+    pub fn history_mode(&self) -> Option<u16> {
+        let mut mode: Option<(u16, usize)> = None;
+        for (face, count) in self.histogram() {
+            if mode.is_none_or(|(_, best)| count > best) {
+                mode = Some((face, count));
+            }
+        }
+        mode.map(|(face, _)| face)
+    }
+
+    #[cfg(feature = "history")]
+    /// Counts the occurrences of each face in the recorded history (only if feature "history" is enabled). Faces which
+    /// have never been rolled are absent.
+    pub fn histogram(&self) -> BTreeMap<u16, usize> {
+        let mut counts = BTreeMap::new();
+        for roll in self.history() {
+            *counts.entry(*roll).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    #[cfg(feature = "history")]
+    /// Counts the occurrences of each face in the recorded history (only if feature "history" is enabled). Unlike
+    /// `histogram`, every face in `faces()` is present, with a count of 0 if it has never been rolled.
+    pub fn histogram_full(&self) -> BTreeMap<u16, usize> {
+        let mut counts: BTreeMap<u16, usize> = self.faces().into_iter().map(|face| (face, 0)).collect();
+        for roll in self.history() {
+            *counts.entry(*roll).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    #[cfg(feature = "history")]
+    /// Renders the recorded history as a text bar chart with one line per face in `faces()`, e.g. ` 3 | ####` (only if
+    /// feature "history" is enabled). Bars are scaled so the most frequent face fills `width` columns, faces which have
+    /// never been rolled get an empty bar.
+    pub fn histogram_ascii(&self, width: usize) -> String {
+        let counts = self.histogram_full();
+        let most = counts.values().copied().max().unwrap_or(0);
+        let label_width = self.max().to_string().len();
+        let mut chart = String::new();
+        for (face, count) in counts {
+            let bar = (count * width).checked_div(most).unwrap_or(0);
+            writeln!(chart, "{:>label_width$} | {}", face, "#".repeat(bar)).expect("writing to a String cannot fail");
+        }
+        chart
+    }
+
+    #[cfg(feature = "history")]
+    /// Finds the longest run of identical consecutive rolls in the history, returning the rolled value and the length
+    /// of the run, or `None` if the history is empty (only if feature "history" is enabled). Ties go to the earliest
+    /// run.
+    pub fn longest_streak(&self) -> Option<(u16, usize)> {
+        self.history()
+            .chunk_by(|a, b| a == b)
+            .fold(None, |longest, run| match longest {
+                Some((_, len)) if len >= run.len() => longest,
+                _ => Some((run[0], run.len())),
+            })
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the run of identical consecutive rolls ending at the most recent roll, returning the rolled value and the
+    /// length of the run, or `None` if the history is empty (only if feature "history" is enabled).
+    pub fn current_streak(&self) -> Option<(u16, usize)> {
+        let run = self.history().chunk_by(|a, b| a == b).next_back()?;
+        Some((run[0], run.len()))
+    }
+
+    #[cfg(feature = "history")]
+    /// Computes the chi-square statistic of the recorded history against a uniform distribution over `faces()` (only
+    /// if feature "history" is enabled). The statistic has `sides - 1` degrees of freedom, which callers need to look
+    /// up the critical value for their chosen significance level. Weights are ignored, so a loaded Die is expected to
+    /// produce a large statistic.
+    ///
+    /// Returns `None` unless there are enough samples for the test to be meaningful, i.e. at least 5 expected rolls of
+    /// every face.
+    pub fn chi_square(&self) -> Option<f64> {
+        let total = self.history().len() as f64;
+        let expected = total / self.sides as f64;
+        if expected < 5.0 {
+            return None;
+        }
+        let statistic = self.histogram_full()
+            .values()
+            .map(|observed| {
+                let diff = *observed as f64 - expected;
+                diff * diff / expected
+            })
+            .sum();
+        Some(statistic)
+    }
+
+    #[cfg(feature = "history")]
+    /// Exports the roll history as CSV with an `index,value` header followed by one row per roll in chronological order
+    /// (only if feature "history" is enabled). An empty history produces just the header.
+    pub fn history_to_csv(&self) -> String {
+        let mut csv = String::new();
+        self.history_to_csv_writer(&mut csv).expect("writing to a String cannot fail");
+        csv
+    }
+
+    #[cfg(feature = "history")]
+    /// Writes the roll history as CSV to `w` without building the whole string first, see `history_to_csv` (only if
+    /// feature "history" is enabled)
+    pub fn history_to_csv_writer<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        writeln!(w, "index,value")?;
+        for (index, roll) in self.history().iter().enumerate() {
+            writeln!(w, "{},{}", index, roll)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the lowest recorded roll, or `None` if the Die hasn't been rolled (only if feature "history" is enabled)
+    pub fn history_min(&self) -> Option<u16> {
+        self.history().iter().copied().min()
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the highest recorded roll, or `None` if the Die hasn't been rolled (only if feature "history" is enabled)
+    pub fn history_max(&self) -> Option<u16> {
+        self.history().iter().copied().max()
+    }
+}
+
+/// The outcome of a single roll, created by `Die::roll_detailed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RollResult {
+    /// The face rolled.
+    pub value: u16,
+    /// The number of sides of the Die rolled.
+    pub sides: u16,
+    /// Whether a critical was rolled, i.e. the highest face unless the Die has a wider `crit_range`.
+    pub is_max: bool,
+    /// Whether the lowest face was rolled (a fumble).
+    pub is_min: bool,
+}
+
+/// The outcome of a batch of rolls, created by `Die::roll_many_detailed`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchResult {
+    /// The faces rolled, in the order they were rolled.
+    pub rolls: Vec<u16>,
+    /// The number of criticals rolled, i.e. rolls of the highest face unless the Die has a wider `crit_range`.
+    pub crits: usize,
+    /// The number of times the lowest face was rolled.
+    pub fumbles: usize,
+    /// The sum of the rolls.
+    pub sum: u64,
+}
+
+#[cfg(feature = "timed_history")]
+/// A recorded roll along with when it was made, see `Die::timed_history` (only if feature "timed_history" is enabled)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedRoll {
+    /// The face rolled.
+    pub value: u16,
+    /// When the roll was made.
+    pub at: Instant,
+}
+
+/// The outcome of a roll against a difficulty, created by `Die::check`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckOutcome {
+    /// A critical was rolled, see `Die::crit_range`.
+    CriticalSuccess,
+    /// The roll was at least the difficulty.
+    Success,
+    /// The roll was below the difficulty.
+    Failure,
+    /// The lowest face was rolled.
+    CriticalFailure,
+}
+
+/// Aggregate statistics of a simulation, created by `Die::simulate`. The optional fields are `None` when no rolls were
+/// simulated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimSummary {
+    /// The number of rolls simulated.
+    pub count: usize,
+    /// The sum of every roll.
+    pub sum: u64,
+    /// The mean roll.
+    pub mean: Option<f64>,
+    /// The lowest roll.
+    pub min: Option<u16>,
+    /// The highest roll.
+    pub max: Option<u16>,
+    /// The number of times each face was rolled. Faces which were never rolled are absent.
+    pub histogram: BTreeMap<u16, usize>,
+}
+
+impl SimSummary {
+    /// Combines two summaries of the same Die into one, as if all of their rolls had been simulated together.
+    pub fn merge(mut self, other: SimSummary) -> SimSummary {
+        self.count += other.count;
+        self.sum += other.sum;
+        self.min = self.min.into_iter().chain(other.min).min();
+        self.max = self.max.into_iter().chain(other.max).max();
+        for (face, count) in other.histogram {
+            *self.histogram.entry(face).or_insert(0) += count;
+        }
+        self.mean = (self.count > 0).then(|| self.sum as f64 / self.count as f64);
+        self
+    }
+}
+
+/// An infinite iterator over rolls of a Die, created by `Die::rolls`.
+pub struct Rolls<'a> {
+    die: &'a mut Die
+}
+
+impl Iterator for Rolls<'_> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        Some(self.die.roll())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// A finite iterator over rolls of a Die it owns, created by `Die::into_rolls`.
+pub struct IntoRolls {
+    die: Die,
+    remaining: usize
+}
+
+impl Iterator for IntoRolls {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(self.die.roll())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for IntoRolls {}
+
+/// An infinite iterator over detailed rolls of a Die, created by `Die::result_iter`.
+pub struct ResultIter<'a> {
+    die: &'a mut Die
+}
+
+impl Iterator for ResultIter<'_> {
+    type Item = RollResult;
+
+    fn next(&mut self) -> Option<RollResult> {
+        Some(self.die.roll_detailed())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// Die Builder
+/// 
+/// This class is used to build a new die. The user has the option of setting the sides and RNG the die will use.
+pub struct DieBuilder {
+    sides: u16,
+    rng: Box<dyn DieRng>,
+    weights: Option<Vec<f64>>,
+    faces: Option<Vec<u16>>,
+    max_explosions: u32,
+    zero_indexed: bool,
+    crit_low: Option<u16>,
+    on_roll: Option<Box<dyn FnMut(u16) + Send + Sync>>,
+    roll_filter: Option<Box<dyn FnMut(u16) -> Option<u16> + Send + Sync>>,
+    #[cfg(feature = "std")]
+    event_sender: Option<Sender<u16>>,
+    #[cfg(feature = "history")]
+    history: Vec<u16>,
+    #[cfg(feature = "history")]
+    history_capacity: usize,
+    #[cfg(feature = "history")]
+    history_reserve: usize
+}
+
+impl fmt::Debug for DieBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("DieBuilder");
+        s.field("sides", &self.sides)
+            .field("rng", &format_args!("<dyn DieRng>"))
+            .field("weights", &self.weights)
+            .field("faces", &self.faces)
+            .field("max_explosions", &self.max_explosions)
+            .field("zero_indexed", &self.zero_indexed)
+            .field("crit_low", &self.crit_low)
+            .field("on_roll", &self.on_roll.as_ref().map(|_| format_args!("<dyn FnMut(u16) + Send + Sync>")))
+            .field("roll_filter", &self.roll_filter.as_ref().map(|_| format_args!("<dyn FnMut(u16) -> Option<u16>>")));
+        #[cfg(feature = "std")]
+        s.field("event_sender", &self.event_sender);
+        #[cfg(feature = "history")]
+        s.field("history", &self.history)
+            .field("history_capacity", &self.history_capacity)
+            .field("history_reserve", &self.history_reserve);
+        s.finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for DieBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<u16> for DieBuilder {
+    /// A DieBuilder for the given number of sides using a standard RNG, equivalent to `DieBuilder::new().sides(sides)`.
+    fn from(sides: u16) -> Self {
+        Self::new().sides(sides)
+    }
+}
+
+impl DieBuilder {
+    #[cfg(feature = "std")]
+    /// Creates a new DieBuilder, which defaults to a 6 sided die using a standard RNG (only if feature "std" is
+    /// enabled).
+    pub fn new() -> DieBuilder {
+        Self::with_rng(Box::new(DieStdRng::new()))
+    }
+
+    /// Creates a new DieBuilder, which defaults to a 6 sided die using the given RNG.
+    pub fn with_rng(rng: Box<dyn DieRng>) -> DieBuilder {
+        Self { 
+            sides: DEFAULT_SIDES,
+            rng,
+            weights: None,
+            faces: None,
+            max_explosions: DEFAULT_MAX_EXPLOSIONS,
+            zero_indexed: false,
+            crit_low: None,
+            on_roll: None,
+            roll_filter: None,
+            #[cfg(feature = "std")]
+            event_sender: None,
+            #[cfg(feature = "history")]
+            history: Vec::new(),
+            #[cfg(feature = "history")]
+            history_capacity: 0,
+            #[cfg(feature = "history")]
+            history_reserve: 0
+        }
+    }
+
+    /// Set the desired number of sides for the Die, up to `u16::MAX`. Default value is used if a value below 2 is
+    /// passed.
+    pub fn sides(mut self, sides: u16) -> DieBuilder {
+        self.sides = sides;
+        self
+    }
+
+    /// Gets the number of sides requested with `sides`. This is the raw value, `build` falls back to the default if
+    /// it's invalid.
+    pub fn get_sides(&self) -> u16 {
+        self.sides
+    }
+
+    /// Set the desired RNG for the Die.
+    pub fn rng(mut self, rng: Box<dyn DieRng>) -> DieBuilder {
+        self.rng = rng;
+        self
+    }
+
+    /// Set the relative weight of each face, making a loaded Die. The number of weights sets the number of sides, and
+    /// face `i + 1` (`i` if zero-indexed) is rolled with probability `weights[i] / sum(weights)`. If the weights are
+    /// invalid (empty, more than `u16::MAX` entries, negative, not finite, summing to infinity, or none positive) the
+    /// Die falls back to a uniform roll.
+    pub fn weights(mut self, weights: Vec<f64>) -> DieBuilder {
+        self.weights = Some(weights);
+        self
+    }
+
+    /// Set an explicit set of faces, e.g. `vec![2, 4, 6, 8]`, each rolled with equal probability. Unlike a LabeledDie
+    /// the faces stay numeric, so the statistics, histograms and probabilities all work with the given values. The
+    /// faces are sorted and duplicates removed, and their number sets the number of sides. Explicit faces take
+    /// precedence over weights and zero indexing. An empty list is invalid and makes `build` ignore it.
+    pub fn faces(mut self, mut faces: Vec<u16>) -> DieBuilder {
+        faces.sort_unstable();
+        faces.dedup();
+        self.faces = Some(faces);
+        self
+    }
+
+    /// Make a constant Die with a single face, e.g. the `1d1` term of a generated formula. Every roll returns 1 (0 if
+    /// zero-indexed) without using the RNG, and is recorded in the history like any other roll. This is equivalent to
+    /// `weights(vec![1.0])`, so a later call to `weights` replaces it, whereas `sides(1)` still falls back to the
+    /// default number of sides.
+    pub fn constant(self) -> DieBuilder {
+        self.weights(vec![1.0])
+    }
+
+    /// Set the maximum number of explosions `Die::roll_exploding` will chain before stopping.
+    pub fn max_explosions(mut self, max_explosions: u32) -> DieBuilder {
+        self.max_explosions = max_explosions;
+        self
+    }
+
+    /// Set whether the Die's faces are numbered `0..=sides - 1` instead of `1..=sides`, e.g. to index into an array. A
+    /// zero-indexed Die reports this through `min`, `max` and `range`, and every roll variant and the history use the
+    /// shifted faces. For a loaded Die the first weight is for face 0.
+    pub fn zero_indexed(mut self, enabled: bool) -> DieBuilder {
+        self.zero_indexed = enabled;
+        self
+    }
+
+    /// Set the lowest face counted as a critical by `Die::roll_detailed`, `Die::check` and `Die::roll_many_detailed`,
+    /// e.g. 19 for a weapon which crits on 19-20. By default only the highest face is a critical. A value outside of
+    /// the Die's faces is invalid and falls back to the default.
+    pub fn crit_range(mut self, low: u16) -> DieBuilder {
+        self.crit_low = Some(low);
+        self
+    }
+
+    #[cfg(feature = "history")]
+    /// Set the maximum number of rolls retained in the history (only if feature "history" is enabled). Once full, the
+    /// oldest roll is discarded to make room for the newest. A capacity of 0 (the default) keeps every roll.
+    pub fn history_capacity(mut self, capacity: usize) -> DieBuilder {
+        self.history_capacity = capacity;
+        self
+    }
+
+    #[cfg(feature = "history")]
+    /// Pre-allocate room for `capacity` rolls in the history, avoiding reallocations when roughly the number of rolls
+    /// is known up front (only if feature "history" is enabled). Unlike `history_capacity` this doesn't bound the
+    /// history, it keeps growing past `capacity` as needed.
+    pub fn reserve_history(mut self, capacity: usize) -> DieBuilder {
+        self.history_reserve = capacity;
+        self
+    }
+
+    #[cfg(feature = "history")]
+    /// Pre-populate the history with previously recorded rolls, e.g. when restoring a saved game (only if feature
+    /// "history" is enabled). See `Die::set_history` for how the rolls are validated.
+    pub fn history(mut self, rolls: Vec<u16>) -> DieBuilder {
+        self.history = rolls;
+        self
+    }
+
+    /// Set a callback invoked with the result of every roll, e.g. to feed analytics or a live UI. The callback runs
+    /// after the roll has been recorded to the history, so it always observes a consistent Die. Like the RNG it must
+    /// be `Send + Sync` so the Die can be shared across threads.
+    pub fn on_roll(mut self, callback: Box<dyn FnMut(u16) + Send + Sync>) -> DieBuilder {
+        self.on_roll = Some(callback);
+        self
+    }
+
+    /// Set a filter which inspects every roll before it is recorded, e.g. to apply a house rule to all rolls.
+    /// Returning `Some(value)` replaces the roll with `value`, which is then filtered again, while `None` accepts it.
+    /// The history, statistics and `on_roll` callback all see the accepted value. To guard against a filter which
+    /// never accepts, the value is taken as is after `MAX_REROLLS` replacements. Replacements aren't checked against
+    /// the Die's faces.
+    ///
+    /// The filter applies to `roll` and every roll method built on it, but not to `peek`, `simulate` or `roll_range`.
+    pub fn roll_filter(mut self, filter: Box<dyn FnMut(u16) -> Option<u16> + Send + Sync>) -> DieBuilder {
+        self.roll_filter = Some(filter);
+        self
+    }
+
+    #[cfg(feature = "std")]
+    /// Set a channel every roll is sent to as it is recorded, after the `on_roll` callback, e.g. to feed a live
+    /// dashboard running on another thread (only if feature "std" is enabled). Once the receiver is dropped the rolls
+    /// are silently discarded, the Die keeps rolling normally.
+    pub fn event_sender(mut self, tx: Sender<u16>) -> DieBuilder {
+        self.event_sender = Some(tx);
+        self
+    }
+
+    /// Set any `rand::RngCore` as the RNG for the Die, wrapping it in a `RandAdapter`.
+    pub fn from_rng<R: RngCore + Clone + Send + Sync + 'static>(self, rng: R) -> DieBuilder {
+        self.rng(Box::new(RandAdapter::new(rng)))
+    }
+
+    /// Set a seeded RNG for the Die, making its rolls reproducible.
+    pub fn seed(self, seed: u64) -> DieBuilder {
+        self.rng(Box::new(DieSeededRng::new(seed)))
+    }
+
+    /// The number of sides the Die will be built with, i.e. the number of faces or weights if valid ones are set,
+    /// otherwise the configured sides or the default if they're invalid.
+    pub fn sides_effective(&self) -> u16 {
+        if let Some(faces) = self.faces_effective() {
+            return faces.len() as u16;
+        }
+        match &self.weights {
+            Some(weights) if Weights::valid(weights) => weights.len() as u16,
+            _ if self.sides > 1 => self.sides,
+            _ => DEFAULT_SIDES,
+        }
+    }
+
+    /// The explicit faces the Die will be built with, if valid ones were set.
+    fn faces_effective(&self) -> Option<&[u16]> {
+        self.faces.as_deref().filter(|faces| !faces.is_empty() && faces.len() <= u16::MAX as usize)
+    }
+
+    /// The range of faces the Die will be built with.
+    fn range_effective(&self) -> RangeInclusive<u16> {
+        if let Some(faces) = self.faces_effective() {
+            return faces[0]..=faces[faces.len() - 1];
+        }
+        let min = if self.zero_indexed { 0 } else { 1 };
+        min..=self.sides_effective() - 1 + min
+    }
+
+    /// Whether the Die will be built with `face` as one of its faces.
+    #[cfg(feature = "history")]
+    fn has_face_effective(&self, face: u16) -> bool {
+        match self.faces_effective() {
+            Some(faces) => faces.binary_search(&face).is_ok(),
+            None => self.range_effective().contains(&face),
+        }
+    }
+
+    /// Checks the current configuration without consuming the Builder, returning the error `try_build` would report.
+    /// This lets callers surface mistakes that `build` would silently replace with defaults.
+    pub fn validate(&self) -> Result<(), DieError> {
+        match (&self.faces, &self.weights) {
+            (Some(_), _) if self.faces_effective().is_none() => return Err(DieError::InvalidFaces),
+            (Some(_), _) => (),
+            (None, Some(weights)) if !Weights::valid(weights) => return Err(DieError::InvalidWeights),
+            (None, None) if self.sides < 2 => return Err(DieError::InvalidSides),
+            _ => (),
+        }
+        if let Some(low) = self.crit_low.filter(|low| !self.range_effective().contains(low)) {
+            return Err(DieError::InvalidCritRange(low));
+        }
+        #[cfg(feature = "history")]
+        if let Some(roll) = self.history.iter().find(|roll| !self.has_face_effective(**roll)) {
+            return Err(DieError::InvalidRoll(*roll));
+        }
+        Ok(())
+    }
+
+    /// Build the Die object, returning an error instead of falling back to defaults if the configuration is invalid.
+    pub fn try_build(self) -> Result<Die, DieError> {
+        self.validate()?;
+        Ok(self.build())
+    }
+
+    /// Build the Die object with the current Builder parameters. A preset history containing invalid rolls is
+    /// discarded.
+    pub fn build(self) -> Die {
+        let sides = self.sides_effective();
+        let range = self.range_effective();
+        let crit_low = self.crit_low.filter(|low| range.contains(low)).unwrap_or(*range.end());
+        let faces = self.faces_effective().map(<[u16]>::to_vec);
+        let weights = if faces.is_some() { None } else { self.weights.and_then(Weights::new) };
+        #[cfg_attr(not(feature = "history"), allow(unused_mut))]
+        let mut die = Die {
+            sides,
+            rng: self.rng,
+            weights,
+            faces,
+            max_explosions: self.max_explosions,
+            zero_indexed: self.zero_indexed,
+            crit_low,
+            total_rolls: 0,
+            on_roll: self.on_roll,
+            roll_filter: self.roll_filter,
+            #[cfg(feature = "std")]
+            event_sender: self.event_sender,
+            #[cfg(feature = "history")]
+            history: BoundedHistory::with_capacity(self.history_reserve),
+            #[cfg(feature = "history")]
+            history_capacity: self.history_capacity,
+            #[cfg(feature = "history")]
+            recorded: 0,
+            #[cfg(feature = "timed_history")]
+            timed_history: BoundedHistory::with_capacity(0),
+            #[cfg(feature = "summary_history")]
+            face_counts: vec![0; sides as usize],
+            #[cfg(feature = "stats")]
+            stats: RunningStats::new()
+        };
+        #[cfg(feature = "history")]
+        if die.set_history(self.history).is_err() {
+            die.history.clear();
+        }
+        die
+    }
+
+    /// Builds `n` Dice with the current Builder parameters, giving die `i` a `DieSeededRng` seeded with
+    /// `base_seed + i` so each die rolls its own stream while the whole set stays reproducible. The Builder's RNG is
+    /// not used, and since closures can't be cloned the dice have no `on_roll` callback or `roll_filter`. An
+    /// `event_sender` is shared by every die.
+    pub fn clone_n(&self, n: usize, base_seed: u64) -> Vec<Die> {
+        (0..n)
+            .map(|i| DieBuilder {
+                sides: self.sides,
+                rng: Box::new(DieSeededRng::new(base_seed.wrapping_add(i as u64))),
+                weights: self.weights.clone(),
+                faces: self.faces.clone(),
+                max_explosions: self.max_explosions,
+                zero_indexed: self.zero_indexed,
+                crit_low: self.crit_low,
+                on_roll: None,
+                roll_filter: None,
+                #[cfg(feature = "std")]
+                event_sender: self.event_sender.clone(),
+                #[cfg(feature = "history")]
+                history: self.history.clone(),
+                #[cfg(feature = "history")]
+                history_capacity: self.history_capacity,
+                #[cfg(feature = "history")]
+                history_reserve: self.history_reserve
+            }.build())
+            .collect()
+    }
+}
+
+/// Errors produced when a Die is misconfigured.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DieError {
+    /// The Die was configured with fewer than 2 sides.
+    InvalidSides,
+    /// A roll outside of the Die's range was supplied, e.g. in a preset history.
+    InvalidRoll(u16),
+    /// The Die was configured with weights which can't be used, see `DieBuilder::weights`.
+    InvalidWeights,
+    /// The Die was configured with an empty (or overly long) list of faces, see `DieBuilder::faces`.
+    InvalidFaces,
+    /// The lowest critical face set with `DieBuilder::crit_range` is not one of the Die's faces.
+    InvalidCritRange(u16),
+    /// The RNG returned a value outside of the Die's range.
+    RngOutOfRange {
+        /// The value returned by the RNG, which is a face index on a Die with explicit faces.
+        got: u16,
+        /// The number of sides of the Die.
+        sides: u16,
+    },
+}
+
+impl fmt::Display for DieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DieError::InvalidSides => write!(f, "a die must have at least 2 sides"),
+            DieError::InvalidRoll(roll) => write!(f, "roll {} is outside of the die's range", roll),
+            DieError::InvalidWeights => {
+                write!(f, "weights must be finite, non-negative, not all zero and at most {} long", u16::MAX)
+            }
+            DieError::InvalidFaces => write!(f, "a die must have between 1 and {} distinct faces", u16::MAX),
+            DieError::InvalidCritRange(low) => write!(f, "critical range start {} is outside of the die's range", low),
+            DieError::RngOutOfRange { got, sides } => write!(f, "rng returned {} for a die with {} sides", got, sides),
+        }
+    }
+}
+
+impl core::error::Error for DieError {}
+
+#[cfg(feature = "history")]
+/// The retained history of a Die, holding only the most recent `capacity` entries if a capacity is given (all of them
+/// if it is 0). Evicted entries are dropped in batches once they make up half the buffer, so pushing is amortized O(1)
+/// while the retained entries stay contiguous.
+#[derive(Clone)]
+struct BoundedHistory<T> {
+    items: Vec<T>,
+    start: usize,
+}
+
+#[cfg(feature = "history")]
+impl<T> BoundedHistory<T> {
+    fn with_capacity(reserve: usize) -> Self {
+        Self { items: Vec::with_capacity(reserve), start: 0 }
+    }
+
+    fn as_slice(&self) -> &[T] {
+        &self.items[self.start..]
+    }
+
+    fn push(&mut self, item: T, capacity: usize) {
+        self.items.push(item);
+        if capacity > 0 && self.items.len() - self.start > capacity {
+            self.start += 1;
+            if self.start >= capacity {
+                self.items.drain(..self.start);
+                self.start = 0;
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.items.len() > self.start {
+            self.items.pop()
+        } else {
+            None
+        }
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.start = 0;
+    }
+
+    /// Replaces the entries with `items`, which must already fit the capacity.
+    fn replace(&mut self, items: Vec<T>) {
+        self.clear();
+        self.items.extend(items);
+    }
+}
+
+/// Relative per-face weights of a loaded Die, along with the distribution used to sample them.
+#[derive(Clone)]
+struct Weights {
+    values: Vec<f64>,
+    index: WeightedIndex<f64>
+}
+
+impl Weights {
+    /// Validates the weights, returning `None` if they can't be used for a Die.
+    fn new(values: Vec<f64>) -> Option<Weights> {
+        if !Self::valid(&values) {
+            return None;
+        }
+        let index = WeightedIndex::new(&values).ok()?;
+        Some(Self { values, index })
+    }
+
+    /// Whether the weights can be used for a Die, i.e. whether `new` would succeed.
+    fn valid(values: &[f64]) -> bool {
+        // WeightedIndex panics rather than erroring on an infinite weight or total, so those are rejected first.
+        values.len() <= u16::MAX as usize
+            && values.iter().all(|w| w.is_finite())
+            && values.iter().sum::<f64>().is_finite()
+            && WeightedIndex::new(values).is_ok()
+    }
+
+    /// The probability of rolling each face, as `(face, probability)` pairs, where the first weight is for face `min`.
+    fn probabilities(&self, min: u16) -> impl Iterator<Item = (u16, f64)> + '_ {
+        let total: f64 = self.values.iter().sum();
+        self.values.iter().enumerate().map(move |(i, w)| (i as u16 + min, w / total))
+    }
+}
+
+/// RNG trait defines an interface for a Random Number Generater. A user can implement their own RNG and pass it to
+/// the DieBuilder method. Implementations must return a value in the inclusive range `l..=h`, i.e. `h` is a valid
+/// result. This interface is UNSAFE, one utilizing the interface could potentially pass bad parameters
+/// i.e. l >= h. The Die will NOT exhibit this behavior.
+///
+/// Generators written for an exclusive upper bound (returning a value in `l..h`) can keep working by overriding
+/// `bound_style` to return `BoundStyle::Exclusive`, in which case every caller in this crate passes `h + 1` instead.
+/// New implementations should use the inclusive default.
+///
+/// `random_int` takes `&mut self` so that stateful generators (e.g. a seeded RNG) can advance their state without
+/// resorting to interior mutability.
+///
+/// Every DieRng must also implement `DieRngClone` so a Die can be cloned. This is implemented automatically for any
+/// RNG which implements `Clone`, so usually `#[derive(Clone)]` is all that's needed.
+///
+/// Every DieRng must also be `Send + Sync`, which makes `Box<dyn DieRng>` and therefore `Die` thread-safe, e.g. a Die
+/// can be shared as an `Arc<Mutex<Die>>`. This is a breaking change for implementations holding thread-local state
+/// such as `Rc` or `RefCell`, which should switch to `Arc` and `Mutex` (or an atomic).
+pub trait DieRng: DieRngClone + Send + Sync {
+    fn random_int(&mut self, l: u16, h: u16) -> u16;
+
+    /// Whether `random_int` treats `h` as an inclusive or exclusive upper bound, defaults to `BoundStyle::Inclusive`.
+    fn bound_style(&self) -> BoundStyle {
+        BoundStyle::Inclusive
+    }
+}
+
+/// The upper bound convention of a DieRng, see `DieRng::bound_style`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BoundStyle {
+    /// `random_int(l, h)` returns a value in `l..=h`.
+    #[default]
+    Inclusive,
+    /// `random_int(l, h)` returns a value in `l..h`. Since `h + 1` must fit in a `u16`, such a generator can never
+    /// produce `u16::MAX`.
+    Exclusive,
+}
+
+/// Draws a value in `l..=h` from `rng`, adjusting the upper bound to the RNG's `bound_style`.
+pub(crate) fn random_inclusive<R: DieRng + ?Sized>(rng: &mut R, l: u16, h: u16) -> u16 {
+    match rng.bound_style() {
+        BoundStyle::Inclusive => rng.random_int(l, h),
+        BoundStyle::Exclusive => rng.random_int(l, h.saturating_add(1)),
+    }
+}
+
+/// Helper trait allowing a boxed DieRng to be cloned. Implemented automatically for every `DieRng + Clone`.
+pub trait DieRngClone {
+    /// Clone the RNG into a new box.
+    fn clone_box(&self) -> Box<dyn DieRng>;
+}
+
+impl<T: DieRng + Clone + 'static> DieRngClone for T {
+    fn clone_box(&self) -> Box<dyn DieRng> {
+        Box::new(self.clone())
+    }
+}
+
+/// An RNG implementation using rand crate functions (only if feature "std" is enabled)
+///
+/// The generator is created (and seeded from the OS) once when the DieStdRng is constructed and then cached, rather
+/// than looking up `rand::thread_rng()` on every roll. This makes `roll_many(1_000_000)` about 40% faster (see
+/// `benches/roll.rs`).
+#[cfg(feature = "std")]
+struct DieStdRng {
+    rng: SmallRng
+}
+
+#[cfg(feature = "std")]
+impl DieStdRng {
+    fn new() -> DieStdRng {
+        Self {
+            rng: SmallRng::from_entropy()
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clone for DieStdRng {
+    /// Clones are freshly seeded, so a cloned Die using the default RNG rolls independently of the original.
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl DieRng for DieStdRng {
+    fn random_int(&mut self, l: u16, h: u16) -> u16 {
+        self.rng.gen_range(l..=h)
+    }
+}
+
+/// A reproducible RNG implementation seeded from a `u64`. Two Die objects using the same seed will produce identical
+/// roll sequences.
+#[derive(Clone)]
+pub struct DieSeededRng {
+    rng: StdRng
+}
+
+impl DieSeededRng {
+    /// Creates a new seeded RNG.
+    pub fn new(seed: u64) -> DieSeededRng {
+        Self {
+            rng: StdRng::seed_from_u64(seed)
+        }
+    }
+}
+
+impl DieRng for DieSeededRng {
+    fn random_int(&mut self, l: u16, h: u16) -> u16 {
+        self.rng.gen_range(l..=h)
+    }
+}
+
+/// An RNG which is loaded toward a single face, e.g. for tutorials or stacked-odds demos. Each roll returns `target`
+/// with probability `bias` and an otherwise uniform roll (which may also be `target`) the rest of the time. If
+/// `target` isn't one of the Die's faces every roll is uniform. Unlike a loaded Die's weights, only one face is
+/// favoured.
+///
+/// A Die with explicit faces asks its RNG for a face index rather than a face, so on such a Die `target` is the
+/// 0-based index into `faces()`. A weighted Die asks for raw random bits, which are never biased, so it rolls
+/// according to its weights alone.
+#[derive(Clone)]
+pub struct LoadedRng {
+    target: u16,
+    bias: f64,
+    rng: StdRng
+}
+
+impl LoadedRng {
+    #[cfg(feature = "std")]
+    /// Creates a new LoadedRng seeded from the OS (only if feature "std" is enabled). Returns `None` unless `bias` is
+    /// within `0.0..=1.0`.
+    pub fn new(target: u16, bias: f64) -> Option<LoadedRng> {
+        Self::with_rng(target, bias, StdRng::from_entropy())
+    }
+
+    /// Creates a new reproducible LoadedRng seeded from a `u64`. Returns `None` unless `bias` is within `0.0..=1.0`.
+    pub fn seeded(target: u16, bias: f64, seed: u64) -> Option<LoadedRng> {
+        Self::with_rng(target, bias, StdRng::seed_from_u64(seed))
+    }
+
+    /// Validates `bias` before wrapping `rng`.
+    fn with_rng(target: u16, bias: f64, rng: StdRng) -> Option<LoadedRng> {
+        if !(0.0..=1.0).contains(&bias) {
+            return None;
+        }
+        Some(Self { target, bias, rng })
+    }
+}
+
+impl DieRng for LoadedRng {
+    fn random_int(&mut self, l: u16, h: u16) -> u16 {
+        // The full range is only requested for the raw bits which drive a weighted Die's sampling.
+        let raw_bits = l == 0 && h == u16::MAX;
+        if !raw_bits && (l..=h).contains(&self.target) && self.rng.gen_bool(self.bias) {
+            return self.target;
+        }
+        self.rng.gen_range(l..=h)
+    }
+}
+
+/// Adapts any `rand::RngCore` (e.g. `StdRng`, `ChaCha20Rng`) into a DieRng, so an existing `rand` generator can be
+/// used by a Die without writing a DieRng implementation. As with every DieRng, the generator must be `Clone`, `Send`
+/// and `Sync`.
+#[derive(Clone)]
+pub struct RandAdapter<R: RngCore> {
+    rng: R
+}
+
+impl<R: RngCore> RandAdapter<R> {
+    /// Wraps the given generator.
+    pub fn new(rng: R) -> RandAdapter<R> {
+        Self { rng }
+    }
+}
+
+impl<R: RngCore + Clone + Send + Sync + 'static> DieRng for RandAdapter<R> {
+    fn random_int(&mut self, l: u16, h: u16) -> u16 {
+        self.rng.gen_range(l..=h)
+    }
+}
+
+/// A deterministic RNG which returns a scripted sequence of values in order, cycling back to the start once exhausted.
+/// This is mostly useful for tests, e.g. scripting the exact rolls for an advantage or exploding roll.
+///
+/// Values outside of the requested range are clamped into it, so a value above the Die's sides rolls the highest face.
+/// An empty sequence always returns the lowest value of the range.
+///
+/// The values only script faces on a plain Die. A Die with explicit faces reads them as 0-based indices into
+/// `faces()`, and a weighted Die reads them as raw random bits for its weighted sampling, so they don't map to faces
+/// directly.
+#[derive(Clone)]
+pub struct SequenceRng {
+    values: Vec<u16>,
+    index: usize
+}
+
+impl SequenceRng {
+    /// Creates a new RNG returning `values` in order.
+    pub fn new(values: Vec<u16>) -> SequenceRng {
+        Self { values, index: 0 }
+    }
+}
+
+impl DieRng for SequenceRng {
+    fn random_int(&mut self, l: u16, h: u16) -> u16 {
+        if self.values.is_empty() {
+            return l;
+        }
+        let ret = self.values[self.index];
+        self.index = (self.index + 1) % self.values.len();
+        ret.clamp(l, h)
+    }
+}
+
+/// Adapts a DieRng into a `rand::RngCore`, so `rand` distributions can be sampled using the Die's own RNG.
+struct DieRngCore<'a>(&'a mut dyn DieRng);
+
+impl RngCore for DieRngCore<'_> {
+    fn next_u32(&mut self) -> u32 {
+        let high = random_inclusive(self.0, 0, u16::MAX) as u32;
+        let low = random_inclusive(self.0, 0, u16::MAX) as u32;
+        (high << 16) | low
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        ((self.next_u32() as u64) << 32) | self.next_u32() as u64
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            let bytes = self.next_u32().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct _DieTerribleRng {}
+    impl DieRng for _DieTerribleRng {
+        fn random_int(&mut self, l: u16, _h: u16) -> u16 {
+            l
+        }
+    }
+
+    #[derive(Clone)]
+    struct _DieMaxRng {}
+    impl DieRng for _DieMaxRng {
+        fn random_int(&mut self, _l: u16, h: u16) -> u16 {
+            h
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn build_and_roll_six_sided_die() {
+        let mut die = DieBuilder::new().sides(6).build();
+        let result = die.roll();
+        assert!((1..=6).contains(&result));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_rng_rolls_highest_face() {
+        let mut die = DieBuilder::new().sides(2).build();
+        let mut seen = [false; 2];
+        for _i in 0..5000 {
+            let result = die.roll();
+            assert!((1..=2).contains(&result));
+            seen[(result - 1) as usize] = true;
+        }
+        assert!(seen[0] && seen[1]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn build_and_roll_thousand_sided_die() {
+        let mut die = DieBuilder::new().sides(1000).build();
+        for _i in 0..1000 {
+            assert!((1..=1000).contains(&die.roll()));
+        }
+        let mut die = DieBuilder::new().sides(1000).rng(Box::new(_DieMaxRng{})).build();
+        assert_eq!(die.roll(), 1000);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn invalid_sides_fall_back_to_default() {
+        let mut die = DieBuilder::new().sides(1).rng(Box::new(_DieMaxRng{})).build();
+        assert_eq!(die.roll(), 6);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn use_custom_rng() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieTerribleRng{})).build();
+        assert_eq!(die.roll(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sequence_rng_cycles() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![3, 1, 6]))).build();
+        assert_eq!(die.roll(), 3);
+        assert_eq!(die.roll(), 1);
+        assert_eq!(die.roll(), 6);
+        assert_eq!(die.roll(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sequence_rng_clamps_to_range() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![0, 9]))).build();
+        assert_eq!(die.roll_many(2), vec![1, 6]);
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(Vec::new()))).build();
+        assert_eq!(die.roll(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_many_returns_each_roll() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieTerribleRng{})).build();
+        assert_eq!(die.roll_many(5), vec![1; 5]);
+        assert!(die.roll_many(0).is_empty());
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn roll_many_records_history() {
+        let mut die = DieBuilder::new().sides(6).build();
+        die.roll();
+        let rolls = die.roll_many(5);
+        let history = die.get_history();
+        assert_eq!(history.len(), 6);
+        assert_eq!(&history[1..], &rolls[..]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn same_seed_rolls_same_sequence() {
+        let mut a = DieBuilder::new().sides(20).seed(42).build();
+        let mut b = DieBuilder::new().sides(20).seed(42).build();
+        assert_eq!(a.roll_many(50), b.roll_many(50));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn constant_die_always_rolls_one() {
+        #[derive(Clone)]
+        struct _DiePanicRng {}
+        impl DieRng for _DiePanicRng {
+            fn random_int(&mut self, _l: u16, _h: u16) -> u16 {
+                panic!("a constant Die must not use its RNG");
+            }
+        }
+
+        let mut die = DieBuilder::with_rng(Box::new(_DiePanicRng{})).constant().build();
+        assert_eq!(die.sides(), 1);
+        assert!(die.roll_many(1000).iter().all(|r| *r == 1));
+        #[cfg(feature = "history")]
+        assert_eq!(die.get_history(), vec![1; 1000]);
+        let mut die = DieBuilder::with_rng(Box::new(_DiePanicRng{})).constant().zero_indexed(true).build();
+        assert_eq!(die.roll(), 0);
+        assert_eq!(DieBuilder::new().sides(1).build().sides(), DEFAULT_SIDES);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn weighted_die_favors_heavy_face() {
+        let mut die = DieBuilder::new().weights(vec![9.0, 1.0]).seed(7).build();
+        let rolls = die.roll_many(10000);
+        assert!(rolls.iter().all(|r| (1..=2).contains(r)));
+        let ones = rolls.iter().filter(|r| **r == 1).count();
+        assert!(ones > 8500, "face 1 rolled {} times", ones);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn invalid_weights_fall_back_to_uniform() {
+        let mut die = DieBuilder::new().weights(vec![-1.0, 2.0]).rng(Box::new(_DieMaxRng{})).build();
+        assert_eq!(die.roll(), 6);
+        let mut die = DieBuilder::new().weights(vec![0.0, 0.0]).rng(Box::new(_DieMaxRng{})).build();
+        assert_eq!(die.roll(), 6);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn non_finite_weights_fall_back_to_uniform() {
+        let builder = DieBuilder::new().weights(vec![f64::INFINITY, 1.0]);
+        assert_eq!(builder.validate(), Err(DieError::InvalidWeights));
+        assert_eq!(builder.try_build().err(), Some(DieError::InvalidWeights));
+        let builder = DieBuilder::new().weights(vec![f64::MAX, f64::MAX]);
+        assert_eq!(builder.validate(), Err(DieError::InvalidWeights));
+        let mut die = builder.rng(Box::new(_DieMaxRng{})).build();
+        assert_eq!((die.sides(), die.roll()), (6, 6));
+        assert_eq!(DieBuilder::new().weights(vec![f64::NAN, 1.0]).build().sides(), 6);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_exploding_sums_chain() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![6, 6, 3]))).build();
+        assert_eq!(die.roll_exploding(), 15);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_exploding_is_capped() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieMaxRng{})).max_explosions(3).build();
+        assert_eq!(die.roll_exploding(), 24);
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieMaxRng{})).build();
+        assert_eq!(die.roll_exploding(), 6 * (DEFAULT_MAX_EXPLOSIONS + 1));
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn roll_exploding_records_each_roll() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![6, 6, 3]))).build();
+        die.roll_exploding();
+        assert_eq!(die.get_history(), vec![6, 6, 3]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn advantage_and_disadvantage() {
+        let mut die = DieBuilder::new().sides(20).rng(Box::new(_DieTerribleRng{})).build();
+        assert_eq!(die.roll_advantage(), 1);
+        assert_eq!(die.roll_disadvantage(), 1);
+
+        let mut die = DieBuilder::new().sides(20).rng(Box::new(SequenceRng::new(vec![4, 17]))).build();
+        assert_eq!(die.roll_advantage(), 17);
+        assert_eq!(die.roll_disadvantage(), 4);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn advantage_records_both_rolls() {
+        let mut die = DieBuilder::new().sides(20).rng(Box::new(SequenceRng::new(vec![17, 4]))).build();
+        die.roll_advantage();
+        die.roll_disadvantage();
+        assert_eq!(die.get_history(), vec![17, 4, 17, 4]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reroll_if_rerolls_once() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![1, 1, 4]))).build();
+        assert_eq!(die.roll_reroll_if(|r| r == 1), 1);
+        assert_eq!(die.roll_reroll_if(|r| r == 1), 4);
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![5, 2]))).build();
+        assert_eq!(die.roll_reroll_if(|r| r == 1), 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reroll_below_rerolls_once() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![2, 1, 3]))).build();
+        assert_eq!(die.roll_reroll_below(3), 1);
+        assert_eq!(die.roll_reroll_below(3), 3);
+        assert_eq!(die.total_rolls(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reroll_while_rerolls_until_false() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![1, 2, 1, 4]))).build();
+        assert_eq!(die.roll_reroll_while(|r| r < 3), 4);
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieTerribleRng{})).build();
+        assert_eq!(die.roll_reroll_while(|_| true), 1);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn reroll_records_each_roll() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![1, 3]))).build();
+        assert_eq!(die.roll_reroll_if(|r| r == 1), 3);
+        assert_eq!(die.get_history(), vec![1, 3]);
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieTerribleRng{})).build();
+        die.roll_reroll_while(|_| true);
+        assert_eq!(die.get_history().len(), MAX_REROLLS as usize + 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn keep_highest_and_lowest() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![3, 6, 1, 4]))).build();
+        assert_eq!(die.roll_keep_highest(4, 3), vec![6, 4, 3]);
+        assert_eq!(die.roll_keep_lowest(4, 3), vec![1, 3, 4]);
+        assert_eq!(die.roll_keep_highest(4, 5), vec![6, 4, 3, 1]);
+        assert!(die.roll_keep_lowest(4, 0).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn drop_lowest_and_highest() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![2, 5, 1, 6, 3]))).build();
+        assert_eq!(die.roll_drop_lowest(5, 2), vec![6, 5, 3]);
+        assert_eq!(die.roll_drop_highest(5, 2), vec![1, 2, 3]);
+        assert_eq!(die.roll_drop_lowest(5, 0).len(), 5);
+        assert!(die.roll_drop_highest(5, 5).is_empty());
+        assert!(die.roll_drop_lowest(5, 9).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn best_and_worst_of() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![3, 6, 1, 6, 1, 4]))).build();
+        assert_eq!(die.roll_best_of(3), (1, 6));
+        assert_eq!(die.roll_worst_of(3), (1, 1));
+        assert_eq!(die.roll_best_of(1), (0, 3));
+        assert_eq!(die.total_rolls(), 7);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic]
+    fn best_of_nothing_panics() {
+        DieBuilder::new().build().roll_best_of(0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_sorted() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![3, 6, 1, 4]))).build();
+        assert_eq!(die.roll_sorted(4), vec![1, 3, 4, 6]);
+        assert_eq!(die.roll_sorted_desc(4), vec![6, 4, 3, 1]);
+        assert!(die.roll_sorted(0).is_empty());
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn roll_sorted_records_roll_order() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![5, 2, 4]))).build();
+        assert_eq!(die.roll_sorted(3), vec![2, 4, 5]);
+        assert_eq!(die.get_history(), vec![5, 2, 4]);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn drop_lowest_records_all_rolls() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![2, 5, 1, 6, 3]))).build();
+        die.roll_drop_lowest(5, 5);
+        assert_eq!(die.get_history(), vec![2, 5, 1, 6, 3]);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn keep_highest_records_all_rolls() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![3, 6, 1, 4]))).build();
+        die.roll_keep_highest(4, 3);
+        assert_eq!(die.get_history(), vec![3, 6, 1, 4]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn keep_highest_on_weighted_die() {
+        let mut die = DieBuilder::new().weights(vec![1.0, 1.0, 1.0, 1.0, 1.0, 20.0]).seed(11).build();
+        let mut kept_sixes = 0;
+        for _i in 0..200 {
+            let kept = die.roll_keep_highest(4, 2);
+            assert_eq!(kept.len(), 2);
+            assert!(kept[0] >= kept[1] && (1..=6).contains(&kept[1]));
+            kept_sixes += kept.iter().filter(|&&r| r == 6).count();
+        }
+        // A face loaded at 20/25 should fill nearly every kept slot out of four rolls.
+        assert!(kept_sixes > 360, "only {} of 400 kept rolls were sixes", kept_sixes);
+
+        #[cfg(feature = "history")]
+        {
+            let history = die.get_history();
+            assert_eq!(history.len(), 800);
+            let sixes = history.iter().filter(|&&r| r == 6).count();
+            assert!((560..=720).contains(&sixes), "{} of 800 recorded rolls were sixes", sixes);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn clone_of_default_rng_is_reseeded() {
+        let mut die = DieBuilder::new().sides(1000).build();
+        let mut clone = die.clone();
+        assert_ne!(die.roll_many(10), clone.roll_many(10));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn clone_duplicates_rng_state() {
+        let mut die = DieBuilder::new().sides(20).seed(3).build();
+        die.roll();
+        let mut clone = die.clone();
+        assert_eq!(clone.roll_many(10), die.roll_many(10));
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn clone_history_is_independent() {
+        let mut die = DieBuilder::new().sides(6).build();
+        die.roll_many(2);
+        let mut clone = die.clone();
+        assert_eq!(clone.get_history(), die.get_history());
+        clone.roll_many(3);
+        die.roll();
+        assert_eq!(die.get_history().len(), 3);
+        assert_eq!(clone.get_history().len(), 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn equality_ignores_rng() {
+        let a = DieBuilder::new().sides(6).build();
+        let b = DieBuilder::new().sides(6).rng(Box::new(_DieMaxRng{})).build();
+        assert!(a == b);
+        assert!(a != DieBuilder::new().sides(8).build());
+        assert!(a != DieBuilder::new().weights(vec![1.0; 6]).build());
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn equality_compares_history() {
+        let mut a = DieBuilder::new().sides(6).build();
+        let b = DieBuilder::new().sides(6).build();
+        assert!(a == b);
+        a.roll();
+        assert!(a != b);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn equal_dice_hash_equally() {
+        let mut set = std::collections::HashSet::new();
+        set.insert(DieBuilder::new().sides(6).build());
+        set.insert(DieBuilder::new().sides(6).rng(Box::new(_DieMaxRng{})).build());
+        assert_eq!(set.len(), 1);
+        set.insert(DieBuilder::new().weights(vec![1.0; 6]).build());
+        set.insert(DieBuilder::new().weights(vec![-0.0, 1.0]).build());
+        set.insert(DieBuilder::new().weights(vec![0.0, 1.0]).build());
+        assert_eq!(set.len(), 3);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn longest_and_current_streak() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![4, 4, 4, 2, 2]))).build();
+        assert_eq!(die.longest_streak(), None);
+        assert_eq!(die.current_streak(), None);
+        die.roll_many(5);
+        assert_eq!(die.longest_streak(), Some((4, 3)));
+        assert_eq!(die.current_streak(), Some((2, 2)));
+        die.roll_many(2);
+        assert_eq!((die.longest_streak(), die.current_streak()), (Some((4, 3)), Some((4, 2))));
+        let die = DieBuilder::new().sides(6).history(vec![1, 3, 3, 5, 5]).build();
+        assert_eq!((die.longest_streak(), die.current_streak()), (Some((3, 2)), Some((5, 2))));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn debug_and_display() {
+        let builder = DieBuilder::new().sides(20);
+        let debug = format!("{:?}", builder);
+        assert!(debug.contains("DieBuilder"));
+        assert!(debug.contains("sides: 20"));
+        assert!(debug.contains("rng: <dyn DieRng>"));
+
+        let die = builder.build();
+        let debug = format!("{:?}", die);
+        assert!(debug.contains("sides: 20"));
+        assert!(debug.contains("rng: <dyn DieRng>"));
+        assert_eq!(die.to_string(), "d20");
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn debug_shows_history_length() {
+        let mut die = DieBuilder::new().sides(6).build();
+        die.roll_many(3);
+        assert!(format!("{:?}", die).contains("history_len: 3"));
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn history_statistics() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![2, 5, 5, 2, 6]))).build();
+        assert_eq!(die.history_mean(), None);
+        assert_eq!(die.history_mode(), None);
+        assert_eq!(die.history_min(), None);
+        assert_eq!(die.history_max(), None);
+
+        die.roll_many(5);
+        assert_eq!(die.history_mean(), Some(4.0));
+        assert_eq!(die.history_mode(), Some(2));
+        assert_eq!(die.history_min(), Some(2));
+        assert_eq!(die.history_max(), Some(6));
+
+        die.roll();
+        assert_eq!(die.history_mode(), Some(2));
+        die.roll_many(2);
+        assert_eq!(die.history_mode(), Some(5));
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn bounded_history_keeps_latest_rolls() {
+        let rng = SequenceRng::new(vec![1, 2, 3, 4, 5]);
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(rng)).history_capacity(3).build();
+        die.roll_many(5);
+        assert_eq!(die.get_history(), vec![3, 4, 5]);
+
+        let mut die = DieBuilder::new().sides(6).history_capacity(0).build();
+        die.roll_many(5);
+        assert_eq!(die.get_history().len(), 5);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn bounded_history_evicts_in_batches() {
+        let rng = SequenceRng::new((1..=6).collect());
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(rng)).history_capacity(3).build();
+        let mut rolls = Vec::new();
+        for _i in 0..20 {
+            rolls.push(die.roll());
+            assert_eq!(die.history(), &rolls[rolls.len().saturating_sub(3)..]);
+            assert!(die.history.items.len() < 6);
+        }
+        assert_eq!(die.undo_last(), Some(2));
+        assert_eq!(die.history(), [6, 1]);
+        die.roll();
+        assert_eq!(die.history(), [6, 1, 3]);
+        while die.undo_last().is_some() {}
+        assert!(die.history().is_empty());
+        #[cfg(feature = "timed_history")]
+        assert!(die.timed_history().is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn on_roll_observes_every_roll() {
+        use std::sync::{Arc, Mutex};
+
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&observed);
+        let mut die = DieBuilder::new()
+            .sides(6)
+            .rng(Box::new(SequenceRng::new(vec![4, 1, 6])))
+            .on_roll(Box::new(move |roll| sink.lock().unwrap().push(roll)))
+            .build();
+        let mut rolls = vec![die.roll()];
+        rolls.extend(die.roll_many(4));
+        assert_eq!(*observed.lock().unwrap(), rolls);
+        assert_eq!(rolls, vec![4, 1, 6, 4, 1]);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn roll_filter_overrides_recorded_rolls() {
+        let mut die = DieBuilder::new()
+            .sides(6)
+            .rng(Box::new(SequenceRng::new(vec![1, 3, 1, 5])))
+            .roll_filter(Box::new(|roll| (roll == 1).then_some(6)))
+            .build();
+        assert_eq!(die.roll_many(8), vec![6, 3, 6, 5, 6, 3, 6, 5]);
+        assert!(!die.history().contains(&1));
+        assert_eq!(die.peek(), 1);
+
+        let mut die = DieBuilder::new().roll_filter(Box::new(|roll| Some(roll % 6 + 1))).build();
+        assert!(die.range().contains(&die.roll()));
+        assert!(die.clone().roll_filter.is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn event_sender_receives_rolls() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut die = DieBuilder::new()
+            .sides(6)
+            .rng(Box::new(SequenceRng::new(vec![4, 1, 6])))
+            .event_sender(tx)
+            .build();
+        let rolls = die.roll_many(4);
+        let handle = std::thread::spawn(move || rx.iter().collect::<Vec<_>>());
+        let mut clone = die.clone();
+        clone.roll();
+        drop((die, clone));
+        assert_eq!(handle.join().unwrap(), vec![4, 1, 6, 4, 1]);
+        assert_eq!(rolls, vec![4, 1, 6, 4]);
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut die = DieBuilder::new().event_sender(tx).build();
+        drop(rx);
+        assert!(die.range().contains(&die.roll()));
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn on_roll_runs_after_history() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let mut die = DieBuilder::new().on_roll(Box::new(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        })).build();
+        die.roll_many(3);
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+        assert!(die.clone().on_roll.is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_until_stops_at_first_match() {
+        let mut die = DieBuilder::new().sides(20).rng(Box::new(SequenceRng::new(vec![4, 11, 20, 20]))).build();
+        assert_eq!(die.roll_until(|r| r == 20, 10), (Some(20), 3));
+        assert_eq!(die.total_rolls(), 3);
+        assert_eq!(die.roll_until(|r| r == 1, 5), (None, 5));
+        assert_eq!(die.roll_until(|_| true, 0), (None, 0));
+        assert_eq!(die.total_rolls(), 8);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn die_is_shareable_across_threads() {
+        use std::sync::{Arc, Mutex};
+        use std::thread;
+
+        let die = Arc::new(Mutex::new(DieBuilder::new().sides(20).build()));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let die = Arc::clone(&die);
+                thread::spawn(move || {
+                    for _i in 0..25 {
+                        let roll = die.lock().unwrap().roll();
+                        assert!((1..=20).contains(&roll));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(die.lock().unwrap().total_rolls(), 100);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn peek_is_not_recorded() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![2, 5]))).build();
+        assert_eq!(die.peek(), 2);
+        assert_eq!(die.total_rolls(), 0);
+        assert_eq!(die.roll(), 5);
+        let mut die = DieBuilder::new().sides(4).build();
+        for _i in 0..100 {
+            assert!(die.range().contains(&die.peek()));
+        }
+        assert_eq!(die.total_rolls(), 0);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn peek_leaves_history_unchanged() {
+        let mut die = DieBuilder::new().build();
+        die.roll();
+        die.peek();
+        assert_eq!(die.get_history().len(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn clone_n_is_reproducible() {
+        let builder = DieBuilder::new().sides(20).crit_range(19);
+        let first = builder.clone_n(4, 100);
+        assert_eq!(first.len(), 4);
+        assert!(first.iter().all(|die| die.sides() == 20 && die.crit_range() == (19..=20)));
+        let rolls = |mut dice: Vec<Die>| dice.iter_mut().map(|die| die.roll_many(50)).collect::<Vec<_>>();
+        let first = rolls(first);
+        assert_eq!(first, rolls(builder.clone_n(4, 100)));
+        assert_ne!(first[0], first[1]);
+        assert_eq!(first[1], rolls(builder.clone_n(1, 101))[0]);
+        assert!(builder.clone_n(0, 100).is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn explicit_faces() {
+        let mut die = DieBuilder::new().faces(vec![8, 2, 6, 4, 4]).seed(3).build();
+        assert_eq!((die.sides(), die.faces(), die.range()), (4, vec![2, 4, 6, 8], 2..=8));
+        let rolls = die.roll_many(1000);
+        assert!(rolls.iter().all(|r| [2, 4, 6, 8].contains(r)));
+        assert!([2, 4, 6, 8].iter().all(|face| rolls.contains(face)));
+        assert_eq!(die.expected_value(), 5.0);
+        assert_eq!(die.variance(), 5.0);
+        assert_eq!(die.probability_at_least(5), 0.5);
+        assert_eq!(die.pmf().into_iter().collect::<Vec<_>>(), vec![(2, 0.25), (4, 0.25), (6, 0.25), (8, 0.25)]);
+        assert_eq!(die.crit_range(), 8..=8);
+        #[cfg(feature = "history")]
+        {
+            assert_eq!(die.histogram_full().keys().copied().collect::<Vec<_>>(), vec![2, 4, 6, 8]);
+            assert!((die.history_mean().unwrap() - 5.0).abs() < 0.3);
+            assert_eq!(die.set_history(vec![2, 3]), Err(DieError::InvalidRoll(3)));
+        }
+        #[cfg(feature = "summary_history")]
+        assert_eq!(die.face_counts().iter().sum::<u64>(), 1000);
+
+        let builder = DieBuilder::new().sides(20).faces(Vec::new());
+        assert_eq!(builder.validate(), Err(DieError::InvalidFaces));
+        assert_eq!(builder.build().faces(), (1..=20).collect::<Vec<_>>());
+        let die = DieBuilder::new().weights(vec![1.0, 0.0]).zero_indexed(true).faces(vec![5, 10]).build();
+        assert_eq!((die.min(), die.max(), die.expected_value()), (5, 10, 7.5));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn percentile_die_rolls_one_to_hundred() {
+        let mut die = Die::percentile();
+        assert_eq!(die.sides(), 100);
+        let rolls = die.roll_many(5000);
+        assert!(rolls.iter().all(|r| (1..=100).contains(r)));
+        assert!(rolls.contains(&1) && rolls.contains(&100));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn default_die_is_d6() {
+        #[derive(Default)]
+        struct Table {
+            die: Die,
+        }
+
+        let mut table = Table::default();
+        assert_eq!(table.die.range(), 1..=6);
+        for _i in 0..100 {
+            assert!((1..=6).contains(&table.die.roll()));
+        }
+        assert_eq!(Die::default().to_string(), "d6");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sides_accessors() {
+        let builder = DieBuilder::new().sides(12);
+        assert_eq!(builder.get_sides(), 12);
+        assert_eq!(builder.build().sides(), 12);
+        assert_eq!(DieBuilder::new().sides(1).build().sides(), DEFAULT_SIDES);
+        assert_eq!(DieBuilder::new().weights(vec![1.0; 3]).build().sides(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn check_outcomes() {
+        let mut die = DieBuilder::new().sides(20).rng(Box::new(SequenceRng::new(vec![20, 15, 14, 1]))).build();
+        assert_eq!(die.check(15), CheckOutcome::CriticalSuccess);
+        assert_eq!(die.check(15), CheckOutcome::Success);
+        assert_eq!(die.check(15), CheckOutcome::Failure);
+        assert_eq!(die.check(15), CheckOutcome::CriticalFailure);
+        assert_eq!(die.check(25), CheckOutcome::CriticalSuccess);
+        assert_eq!(die.check(0), CheckOutcome::Success);
+        assert_eq!(die.total_rolls(), 6);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_side_count() {
+        let d20: Die = 20.into();
+        assert_eq!(d20.sides(), 20);
+        assert_eq!(Die::from(0).sides(), DEFAULT_SIDES);
+        assert_eq!(DieBuilder::from(12).max_explosions(3).build().sides(), 12);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reseed_restarts_stream() {
+        let mut a = DieBuilder::new().sides(20).build();
+        let mut b = DieBuilder::new().sides(20).rng(Box::new(_DieMaxRng{})).build();
+        a.roll_many(3);
+        a.reseed(99);
+        b.reseed(99);
+        assert_eq!(a.roll_many(10), b.roll_many(10));
+        let mut fresh = DieBuilder::new().sides(20).seed(99).build();
+        b.reseed(99);
+        assert_eq!(b.roll_many(10), fresh.roll_many(10));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reset_matches_fresh_die() {
+        let mut die = DieBuilder::new().sides(20).crit_range(19).build();
+        die.roll_many(5);
+        die.reset(Some(7));
+        assert_eq!(die.total_rolls(), 0);
+        #[cfg(feature = "history")]
+        assert!(die.history().is_empty());
+        #[cfg(feature = "stats")]
+        assert_eq!(die.count(), 0);
+        #[cfg(feature = "summary_history")]
+        assert_eq!(die.face_counts(), vec![0; 20]);
+        let mut fresh = DieBuilder::new().sides(20).crit_range(19).seed(7).build();
+        assert_eq!(die.roll_many(10), fresh.roll_many(10));
+        assert!(die == fresh && die.crit_range() == fresh.crit_range());
+
+        die.roll_many(3);
+        die.reset_seeded(7);
+        assert_eq!(die.total_rolls(), 0);
+        assert_eq!(die.roll_many(10), DieBuilder::new().sides(20).seed(7).build().roll_many(10));
+
+        die.reset(None);
+        assert_eq!(die.total_rolls(), 0);
+        assert!(die.range().contains(&die.roll()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_checked_catches_bad_rng() {
+        #[derive(Clone)]
+        struct _DieBrokenRng {}
+        impl DieRng for _DieBrokenRng {
+            fn random_int(&mut self, _l: u16, h: u16) -> u16 {
+                h + 1
+            }
+        }
+
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieBrokenRng{})).build();
+        assert_eq!(die.roll_checked(), Err(DieError::RngOutOfRange { got: 7, sides: 6 }));
+        assert_eq!(die.total_rolls(), 0);
+        assert_eq!(die.roll(), 7);
+        assert_eq!(die.total_rolls(), 1);
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieMaxRng{})).build();
+        assert_eq!(die.roll_checked(), Ok(6));
+
+        let mut die = DieBuilder::new().faces(vec![2, 4, 6, 8]).rng(Box::new(_DieBrokenRng{})).build();
+        assert_eq!(die.roll_checked(), Err(DieError::RngOutOfRange { got: 4, sides: 4 }));
+        assert_eq!(die.total_rolls(), 0);
+        assert_eq!(die.roll(), 8);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_modified() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![5, 2]))).build();
+        assert_eq!(die.roll_modified(3, true), 6);
+        assert_eq!(die.roll_modified(-4, true), 1);
+        assert_eq!(die.roll_modified(3, false), 8);
+        assert_eq!(die.roll_modified(-4, false), 1);
+        assert_eq!(die.roll_modified(0, true), 5);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn roll_modified_records_raw_roll() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![4]))).build();
+        assert_eq!(die.roll_modified(2, false), 6);
+        assert_eq!(die.get_history(), vec![4]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_formatted() {
+        let mut die = DieBuilder::new().sides(20).rng(Box::new(SequenceRng::new(vec![17]))).build();
+        assert_eq!(die.roll_formatted(), "d20: 17");
+        assert_eq!(die.total_rolls(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn into_rolls_consumes_die() {
+        let rolls = Die::default().into_rolls(10).collect::<Vec<_>>();
+        assert_eq!(rolls.len(), 10);
+        assert!(rolls.iter().all(|r| (1..=6).contains(r)));
+        let rolls = DieBuilder::new().sides(4).rng(Box::new(_DieMaxRng{})).build().into_rolls(3);
+        assert_eq!(rolls.len(), 3);
+        assert_eq!(rolls.collect::<Vec<_>>(), vec![4, 4, 4]);
+        assert_eq!(Die::default().into_rolls(0).next(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn result_iterator() {
+        let mut die = DieBuilder::new().sides(20).rng(Box::new(SequenceRng::new(vec![20, 5, 1, 20, 13]))).build();
+        let results: Vec<RollResult> = die.result_iter().take(5).collect();
+        assert_eq!(results.len(), 5);
+        assert_eq!(results.iter().filter(|r| r.is_max).count(), 2);
+        assert_eq!(results.iter().filter(|r| r.is_min).count(), 1);
+        assert_eq!(die.result_iter().take(100).filter(|r| r.is_max).count(), 40);
+        assert_eq!(die.total_rolls(), 105);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn wider_crit_range() {
+        let rng = SequenceRng::new(vec![19, 20, 18, 1]);
+        let mut die = DieBuilder::new().sides(20).crit_range(19).rng(Box::new(rng)).build();
+        assert_eq!(die.crit_range(), 19..=20);
+        assert!(die.roll_detailed().is_max);
+        assert!(die.roll_detailed().is_max);
+        assert!(!die.roll_detailed().is_max);
+        assert!(die.roll_detailed().is_min);
+        assert_eq!(die.check(25), CheckOutcome::CriticalSuccess);
+        assert_eq!(die.roll_many_detailed(4).crits, 2);
+
+        assert_eq!(DieBuilder::new().sides(20).build().crit_range(), 20..=20);
+        let builder = DieBuilder::new().sides(20).crit_range(21);
+        assert_eq!(builder.validate(), Err(DieError::InvalidCritRange(21)));
+        assert_eq!(builder.build().crit_range(), 20..=20);
+        assert_eq!(DieBuilder::new().sides(6).crit_range(0).validate(), Err(DieError::InvalidCritRange(0)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn loaded_rng_favours_target() {
+        assert!(LoadedRng::new(6, 1.5).is_none());
+        assert!(LoadedRng::seeded(6, -0.1, 1).is_none());
+        assert!(LoadedRng::seeded(6, f64::NAN, 1).is_none());
+
+        let rng = LoadedRng::seeded(6, 0.5, 3).unwrap();
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(rng)).build();
+        let sixes = die.roll_many(10_000).into_iter().filter(|r| *r == 6).count();
+        // 0.5 + 0.5 / 6 of the rolls are expected to hit the target
+        assert!((5_500..6_200).contains(&sixes), "{} sixes", sixes);
+
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(LoadedRng::new(6, 1.0).unwrap())).build();
+        assert!(die.roll_many(100).iter().all(|r| *r == 6));
+        let mut die = DieBuilder::new().sides(4).rng(Box::new(LoadedRng::new(6, 1.0).unwrap())).build();
+        assert!(die.roll_many(100).iter().all(|r| (1..=4).contains(r)));
+
+        let rng = LoadedRng::seeded(1, 1.0, 3).unwrap();
+        let mut die = DieBuilder::new().faces(vec![2, 4, 6, 8]).rng(Box::new(rng)).build();
+        assert!(die.roll_many(100).iter().all(|r| *r == 4));
+        let rng = LoadedRng::seeded(1, 1.0, 3).unwrap();
+        let mut die = DieBuilder::new().weights(vec![1.0, 1.0, 1.0]).rng(Box::new(rng)).build();
+        let ones = die.roll_many(3_000).into_iter().filter(|r| *r == 1).count();
+        assert!((800..1_200).contains(&ones), "{} ones", ones);
+    }
+
+    #[cfg(all(feature = "summary_history", feature = "std"))]
+    #[test]
+    fn face_counts_tally_rolls() {
+        let mut die = DieBuilder::new().sides(4).rng(Box::new(SequenceRng::new(vec![1, 3, 3, 4]))).build();
+        assert_eq!(die.face_counts(), vec![0; 4]);
+        die.roll_many(400);
+        assert_eq!(die.face_counts(), vec![100, 0, 200, 100]);
+        die.roll_range(10, 10);
+        assert_eq!(die.face_counts().iter().sum::<u64>(), 400);
+
+        let mut die = DieBuilder::new().sides(3).zero_indexed(true).rng(Box::new(SequenceRng::new(vec![0, 2]))).build();
+        die.roll_many(3);
+        assert_eq!(die.face_counts(), vec![2, 0, 1]);
+        #[cfg(feature = "history")]
+        {
+            die.undo_last();
+            assert_eq!(die.face_counts(), vec![1, 0, 1]);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn bound_styles_cover_all_faces() {
+        #[derive(Clone)]
+        struct _DieExclusiveRng(StdRng);
+        impl DieRng for _DieExclusiveRng {
+            fn random_int(&mut self, l: u16, h: u16) -> u16 {
+                self.0.gen_range(l..h)
+            }
+            fn bound_style(&self) -> BoundStyle {
+                BoundStyle::Exclusive
+            }
+        }
+
+        let exclusive = _DieExclusiveRng(StdRng::seed_from_u64(5));
+        for rng in [Box::new(exclusive) as Box<dyn DieRng>, Box::new(DieSeededRng::new(5))] {
+            let mut die = DieBuilder::new().sides(6).rng(rng).build();
+            let rolls = die.roll_many(1000);
+            assert!(rolls.iter().all(|r| (1..=6).contains(r)));
+            assert!((1..=6).all(|face| rolls.contains(&face)));
+        }
+        assert_eq!(DieSeededRng::new(0).bound_style(), BoundStyle::Inclusive);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn total_rolls_counts_every_roll() {
+        let mut die = DieBuilder::new().sides(6).build();
+        assert_eq!(die.total_rolls(), 0);
+        die.roll();
+        die.roll_advantage();
+        assert_eq!(die.total_rolls(), 3);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn clear_history_keeps_total_rolls() {
+        let mut die = DieBuilder::new().sides(6).build();
+        die.roll_many(4);
+        die.clear_history();
+        assert!(die.get_history().is_empty());
+        die.roll_many(2);
+        assert_eq!(die.get_history().len(), 2);
+        assert_eq!(die.total_rolls(), 6);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn rolls_iterator() {
+        let mut die = DieBuilder::new().sides(6).build();
+        let rolls: Vec<u16> = die.rolls().take(4).collect();
+        assert_eq!(rolls.len(), 4);
+        assert!(rolls.iter().all(|r| (1..=6).contains(r)));
+        assert_eq!(die.total_rolls(), 4);
+        #[cfg(feature = "history")]
+        assert_eq!(die.get_history(), rolls);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn min_max_and_range() {
+        let die = DieBuilder::new().sides(20).build();
+        assert_eq!(die.min(), 1);
+        assert_eq!(die.max(), 20);
+        assert_eq!(die.range(), 1..=20);
+        let die = DieBuilder::new().weights(vec![1.0, 2.0, 3.0]).build();
+        assert_eq!(die.range(), 1..=3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn expected_value_and_variance() {
+        let die = DieBuilder::new().sides(6).build();
+        assert_eq!(die.expected_value(), 3.5);
+        assert!((die.variance() - 35.0 / 12.0).abs() < 1e-12);
+
+        let die = DieBuilder::new().weights(vec![1.0, 0.0, 1.0]).build();
+        assert!((die.expected_value() - 2.0).abs() < 1e-12);
+        assert!((die.variance() - 1.0).abs() < 1e-12);
+
+        let die = DieBuilder::new().weights(vec![1.0; 6]).build();
+        assert!((die.expected_value() - 3.5).abs() < 1e-12);
+        assert!((die.variance() - 35.0 / 12.0).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pmf() {
+        let pmf = DieBuilder::new().sides(4).build().pmf();
+        assert_eq!(pmf.len(), 4);
+        assert!(pmf.values().all(|p| *p == 0.25));
+        assert!((pmf.values().sum::<f64>() - 1.0).abs() < 1e-12);
+
+        let pmf = DieBuilder::new().weights(vec![1.0, 3.0]).zero_indexed(true).build().pmf();
+        assert_eq!(pmf.into_iter().collect::<Vec<_>>(), vec![(0, 0.25), (1, 0.75)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn probability_at_least() {
+        let die = DieBuilder::new().sides(20).build();
+        assert!((die.probability_at_least(15) - 0.3).abs() < 1e-12);
+        assert_eq!(die.probability_at_least(0), 1.0);
+        assert_eq!(die.probability_at_least(1), 1.0);
+        assert_eq!(die.probability_at_least(20), 0.05);
+        assert_eq!(die.probability_at_least(21), 0.0);
+
+        let die = DieBuilder::new().weights(vec![1.0, 1.0, 2.0]).build();
+        assert_eq!(die.probability_at_least(3), 0.5);
+        assert_eq!(die.probability_at_least(4), 0.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn simulate_summarizes_rolls() {
+        let mut die = DieBuilder::new().sides(6).seed(7).build();
+        let summary = die.simulate(1000);
+        assert_eq!(summary.count, 1000);
+        assert!((summary.mean.unwrap() * summary.count as f64 - summary.sum as f64).abs() < 1e-6);
+        assert!(summary.min.unwrap() >= 1 && summary.max.unwrap() <= 6);
+        assert!(summary.min <= summary.max);
+        assert_eq!(summary.histogram.values().sum::<usize>(), 1000);
+        assert_eq!(summary.histogram.iter().map(|(face, n)| *face as u64 * *n as u64).sum::<u64>(), summary.sum);
+        assert_eq!(die.total_rolls(), 0);
+
+        let summary = die.simulate(0);
+        assert_eq!((summary.sum, summary.mean, summary.min, summary.max), (0, None, None, None));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn merged_summaries_match_one_simulation() {
+        let rng = SequenceRng::new(vec![2, 6, 1, 4, 4]);
+        let whole = DieBuilder::new().sides(6).rng(Box::new(rng.clone())).build().simulate(5);
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(rng)).build();
+        let merged = die.simulate(2).merge(die.simulate(0)).merge(die.simulate(3));
+        assert_eq!(merged, whole);
+        assert_eq!((merged.min, merged.max, merged.mean), (Some(1), Some(6), Some(3.4)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn zero_indexed_faces() {
+        let mut die = DieBuilder::new().sides(6).zero_indexed(true).build();
+        assert_eq!((die.min(), die.max(), die.range()), (0, 5, 0..=5));
+        let rolls = die.roll_many(1000);
+        assert!(rolls.iter().all(|r| (0..=5).contains(r)));
+        assert!(rolls.contains(&0));
+        assert_eq!(die.expected_value(), 2.5);
+        assert_eq!(die.probability_at_least(5), 1.0 / 6.0);
+
+        let mut die = DieBuilder::new().weights(vec![0.0, 1.0]).zero_indexed(true).build();
+        assert_eq!(die.roll(), 1);
+        assert_eq!(die.expected_value(), 1.0);
+        let mut die = DieBuilder::new().sides(4).zero_indexed(true).rng(Box::new(_DieMaxRng{})).build();
+        assert_eq!(die.roll_detailed().value, 3);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn zero_indexed_history() {
+        let mut die = DieBuilder::new().sides(6).zero_indexed(true).rng(Box::new(SequenceRng::new(vec![0, 5]))).build();
+        die.roll_many(2);
+        assert_eq!(die.get_history(), vec![0, 5]);
+        assert_eq!(die.histogram_full().keys().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+        assert!(die.set_history(vec![6]).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn validate_reports_effective_configuration() {
+        let builder = DieBuilder::new().sides(0);
+        assert_eq!(builder.validate(), Err(DieError::InvalidSides));
+        assert_eq!(builder.sides_effective(), DEFAULT_SIDES);
+        let builder = builder.sides(20);
+        assert_eq!(builder.validate(), Ok(()));
+        assert_eq!(builder.sides_effective(), 20);
+
+        let builder = DieBuilder::new().sides(20).weights(vec![0.0, 0.0]);
+        assert_eq!(builder.validate(), Err(DieError::InvalidWeights));
+        assert_eq!(builder.sides_effective(), 20);
+        assert_eq!(builder.try_build().err(), Some(DieError::InvalidWeights));
+        let builder = DieBuilder::new().sides(0).weights(vec![1.0, 2.0, 3.0]);
+        assert_eq!(builder.validate(), Ok(()));
+        assert_eq!(builder.sides_effective(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn try_build_rejects_invalid_sides() {
+        assert_eq!(DieBuilder::new().sides(0).try_build().err(), Some(DieError::InvalidSides));
+        assert_eq!(DieBuilder::new().sides(1).try_build().err(), Some(DieError::InvalidSides));
+        assert_eq!(DieBuilder::new().sides(12).sides(0).try_build().err(), Some(DieError::InvalidSides));
+        let mut die = DieBuilder::new().sides(8).rng(Box::new(_DieMaxRng{})).try_build().unwrap();
+        assert_eq!(die.roll(), 8);
+        assert!(DieBuilder::new().try_build().is_ok());
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn history_histogram() {
+        let mut die = DieBuilder::new().sides(4).rng(Box::new(SequenceRng::new(vec![1, 3, 3, 1, 3]))).build();
+        assert!(die.histogram().is_empty());
+        die.roll_many(5);
+        assert_eq!(die.histogram().into_iter().collect::<Vec<_>>(), vec![(1, 2), (3, 3)]);
+        assert_eq!(die.histogram_full().into_iter().collect::<Vec<_>>(), vec![(1, 2), (2, 0), (3, 3), (4, 0)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn rand_adapter_is_deterministic() {
+        let mut a = DieBuilder::new().sides(20).from_rng(StdRng::seed_from_u64(9)).build();
+        let mut b = DieBuilder::new().sides(20).rng(Box::new(RandAdapter::new(StdRng::seed_from_u64(9)))).build();
+        let rolls = a.roll_many(50);
+        assert!(rolls.iter().all(|r| (1..=20).contains(r)));
+        assert_eq!(rolls, b.roll_many(50));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_detailed_flags_max_and_min() {
+        let mut die = DieBuilder::new().sides(20).rng(Box::new(SequenceRng::new(vec![20, 1, 12]))).build();
+        assert_eq!(die.roll_detailed(), RollResult { value: 20, sides: 20, is_max: true, is_min: false });
+        assert_eq!(die.roll_detailed(), RollResult { value: 1, sides: 20, is_max: false, is_min: true });
+        let result = die.roll_detailed();
+        assert!(!result.is_max && !result.is_min);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_range_uses_custom_bounds() {
+        let mut die = DieBuilder::new().sides(6).build();
+        for _i in 0..200 {
+            assert!((50..=60).contains(&die.roll_range(50, 60)));
+        }
+        assert_eq!(die.roll_range(7, 7), 7);
+        assert_eq!(die.total_rolls(), 201);
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieMaxRng{})).build();
+        assert_eq!(die.roll_range(10, 30), 30);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "low (5) > high (2)")]
+    fn roll_range_panics_on_inverted_bounds() {
+        DieBuilder::new().build().roll_range(5, 2);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn roll_range_records_history() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieMaxRng{})).build();
+        die.roll_range(10, 30);
+        assert_eq!(die.get_history(), vec![30]);
+    }
+
+    #[cfg(all(feature = "stats", feature = "std"))]
+    #[test]
+    fn running_stats_track_rolls() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![1, 2, 6, 3]))).build();
+        assert_eq!(die.running_mean(), None);
+        let rolls = die.roll_many(4);
+        let mean = rolls.iter().map(|r| *r as f64).sum::<f64>() / rolls.len() as f64;
+        assert_eq!(die.count(), 4);
+        assert!((die.running_mean().unwrap() - mean).abs() < 1e-12);
+        assert!((die.running_variance().unwrap() - 14.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn chi_square_detects_bias() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![1, 2, 3, 4, 5, 6]))).build();
+        die.roll_many(24);
+        assert_eq!(die.chi_square(), None);
+        die.roll_many(576);
+        assert!(die.chi_square().unwrap() < 1e-9);
+
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![6, 6, 6, 1]))).build();
+        die.roll_many(600);
+        // 11.07 is the 95% critical value with 5 degrees of freedom.
+        assert!(die.chi_square().unwrap() > 11.07);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn history_to_csv() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![3, 6, 1]))).build();
+        assert_eq!(die.history_to_csv(), "index,value\n");
+        die.roll_many(3);
+        assert_eq!(die.history_to_csv(), "index,value\n0,3\n1,6\n2,1\n");
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn preset_history() {
+        let mut die = DieBuilder::new().sides(6).history(vec![2, 6, 1]).build();
+        assert_eq!(die.get_history(), vec![2, 6, 1]);
+        die.roll();
+        assert_eq!(die.get_history().len(), 4);
+
+        assert_eq!(die.set_history(vec![3, 7]), Err(DieError::InvalidRoll(7)));
+        assert_eq!(die.get_history().len(), 4);
+        assert_eq!(DieBuilder::new().sides(6).history(vec![0]).try_build().err(), Some(DieError::InvalidRoll(0)));
+        assert!(DieBuilder::new().sides(6).history(vec![0]).build().get_history().is_empty());
+
+        let die = DieBuilder::new().history_capacity(2).history(vec![1, 2, 3]).build();
+        assert_eq!(die.get_history(), vec![2, 3]);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn history_sum_and_len() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![3, 6, 1, 5]))).build();
+        assert_eq!((die.history_sum(), die.history_len()), (0, 0));
+        die.roll_many(6);
+        assert_eq!((die.history_sum(), die.history_len()), (24, 6));
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn undo_last_pops_latest_roll() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![2, 5]))).build();
+        die.roll_many(2);
+        assert_eq!(die.undo_last(), Some(5));
+        assert_eq!(die.get_history(), vec![2]);
+        assert_eq!(die.total_rolls(), 1);
+        assert_eq!(die.undo_last(), Some(2));
+        assert_eq!(die.undo_last(), None);
+        assert_eq!(die.total_rolls(), 0);
+
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieMaxRng{})).build();
+        die.roll_many(2);
+        die.set_history(vec![1]).unwrap();
+        die.roll();
+        assert_eq!(die.undo_last(), Some(6));
+        assert_eq!(die.total_rolls(), 2);
+        assert_eq!(die.undo_last(), Some(1));
+        assert_eq!(die.total_rolls(), 2);
+        #[cfg(feature = "stats")]
+        assert_eq!(die.running_mean(), Some(6.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_many_detailed_tallies() {
+        let mut die = DieBuilder::new().sides(20).rng(Box::new(SequenceRng::new(vec![20, 1, 7, 20, 1, 1]))).build();
+        let batch = die.roll_many_detailed(6);
+        assert_eq!(batch.rolls, vec![20, 1, 7, 20, 1, 1]);
+        assert_eq!((batch.crits, batch.fumbles, batch.sum), (2, 3, 50));
+        assert_eq!(die.total_rolls(), 6);
+        assert_eq!(die.roll_many_detailed(0), BatchResult { rolls: vec![], crits: 0, fumbles: 0, sum: 0 });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_into_fills_buffer() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![3, 6, 1]))).build();
+        let mut buf = [0u16; 5];
+        die.roll_into(&mut buf);
+        assert_eq!(buf, [3, 6, 1, 3, 6]);
+        assert_eq!(die.total_rolls(), 5);
+        die.roll_into(&mut []);
+        assert_eq!(die.total_rolls(), 5);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn roll_into_records_each_roll() {
+        let mut die = DieBuilder::new().build();
+        let mut buf = [0u16; 5];
+        die.roll_into(&mut buf);
+        assert!(buf.iter().all(|r| (1..=6).contains(r)));
+        assert_eq!(die.get_history(), buf);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn histogram_ascii() {
+        let mut die = DieBuilder::new().sides(10).rng(Box::new(SequenceRng::new(vec![10, 10, 10, 10, 2, 2]))).build();
+        die.roll_many(6);
+        let chart = die.histogram_ascii(8);
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[0], " 1 | ");
+        assert_eq!(lines[1], " 2 | ####");
+        assert_eq!(lines[9], "10 | ########");
+        assert_eq!(DieBuilder::new().sides(2).build().histogram_ascii(5), "1 | \n2 | \n");
+    }
+
+    #[cfg(feature = "timed_history")]
+    #[test]
+    fn timed_history_records_instants() {
+        let before = Instant::now();
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![2, 5]))).build();
+        die.roll_many(2);
+        let timed = die.timed_history();
+        assert_eq!(timed.iter().map(|t| t.value).collect::<Vec<_>>(), die.get_history());
+        assert!(timed[0].at >= before);
+        assert!(timed[1].at >= timed[0].at);
+        die.undo_last();
+        assert_eq!(die.timed_history().len(), 1);
+        die.set_history(vec![1, 2, 3]).unwrap();
+        assert!(die.timed_history().is_empty());
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn history_variance_and_std_dev() {
+        let mut die = DieBuilder::new().sides(10).rng(Box::new(SequenceRng::new(vec![2, 4, 4, 4, 5, 5, 7, 9]))).build();
+        die.roll();
+        assert_eq!(die.history_variance(), None);
+        assert_eq!(die.history_std_dev(), None);
+        die.roll_many(7);
+        assert!((die.history_variance().unwrap() - 32.0 / 7.0).abs() < 1e-12);
+        assert!((die.history_std_dev().unwrap() - 2.138_089_935).abs() < 1e-9);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn reserve_history_preallocates() {
+        let mut die = DieBuilder::new().reserve_history(1000).build();
+        die.roll();
+        assert!(die.history.items.capacity() >= 1000);
+        assert_eq!(die.history_len(), 1);
+        let die = DieBuilder::new().reserve_history(1000).history(vec![1, 2]).build();
+        assert!(die.history.items.capacity() >= 1000);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn get_history() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(_DieTerribleRng{})).build();
+        for _i in 0..2 {
+            die.roll();
+        }
+        let history = die.get_history();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0], 1);
+    }
+
+    #[cfg(all(feature = "history", feature = "std"))]
+    #[test]
+    fn history_borrows_rolls() {
+        let mut die = DieBuilder::new().sides(6).rng(Box::new(SequenceRng::new(vec![3, 5]))).build();
+        assert!(die.history().is_empty());
+        die.roll_many(3);
+        let history: &[u16] = die.history();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history, [3, 5, 3]);
+        assert_eq!(history.as_ptr(), die.history().as_ptr());
     }
 }
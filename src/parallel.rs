@@ -0,0 +1,71 @@
+//! Parallel rolling across threads (only if feature "rayon" is enabled).
+use rayon::prelude::*;
+
+use crate::{DieBuilder, DieRng, DieSeededRng, DieStdRng, SimSummary, DEFAULT_SIDES};
+
+/// The number of rolls simulated with each seed by `simulate_parallel_seeded`.
+const SEEDED_CHUNK: usize = 1 << 16;
+
+/// Rolls a `sides` sided die `rolls` times, spreading the work across rayon's thread pool. The default is used if
+/// `sides` is below 2.
+///
+/// Each worker uses its own RNG so there is no contention between threads. Because of this, and because history is
+/// inherently sequential, no history is recorded. The results are not reproducible from run to run.
+pub fn simulate_parallel(sides: u16, rolls: usize) -> Vec<u16> {
+    let sides = if sides > 1 { sides } else { DEFAULT_SIDES };
+    (0..rolls)
+        .into_par_iter()
+        .map_init(DieStdRng::new, |rng, _| rng.random_int(1, sides))
+        .collect()
+}
+
+/// Rolls a `sides` sided die `rolls` times like `simulate_parallel`, but reproducibly, returning a summary of the
+/// results. The default is used if `sides` is below 2.
+///
+/// The rolls are split into fixed size chunks, each simulated with a `DieSeededRng` whose seed is derived from
+/// `base_seed` and the chunk's index, and the chunk summaries are merged. The result therefore only depends on the
+/// arguments, not on the number of threads or how rayon schedules the chunks.
+pub fn simulate_parallel_seeded(sides: u16, rolls: usize, base_seed: u64) -> SimSummary {
+    let sides = if sides > 1 { sides } else { DEFAULT_SIDES };
+    let empty = || DieBuilder::with_rng(Box::new(DieSeededRng::new(base_seed))).sides(sides).build().simulate(0);
+    (0..rolls.div_ceil(SEEDED_CHUNK))
+        .into_par_iter()
+        .map(|chunk| {
+            // Spread the chunk index over the seed so nearby base seeds don't share chunk streams.
+            let seed = base_seed ^ (chunk as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+            let len = SEEDED_CHUNK.min(rolls - chunk * SEEDED_CHUNK);
+            DieBuilder::with_rng(Box::new(DieSeededRng::new(seed))).sides(sides).build().simulate(len)
+        })
+        .reduce(empty, SimSummary::merge)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simulate_parallel_rolls_in_range() {
+        let rolls = simulate_parallel(20, 100_000);
+        assert_eq!(rolls.len(), 100_000);
+        assert!(rolls.iter().all(|r| (1..=20).contains(r)));
+        assert!(rolls.contains(&1) && rolls.contains(&20));
+        assert!(simulate_parallel(20, 0).is_empty());
+    }
+
+    #[test]
+    fn simulate_parallel_seeded_is_reproducible() {
+        let rolls = 3 * SEEDED_CHUNK + 17;
+        let summary = simulate_parallel_seeded(20, rolls, 42);
+        assert_eq!(summary, simulate_parallel_seeded(20, rolls, 42));
+        assert_ne!(summary, simulate_parallel_seeded(20, rolls, 43));
+
+        assert_eq!(summary.count, rolls);
+        assert_eq!(summary.histogram.values().sum::<usize>(), rolls);
+        assert_eq!(summary.sum, summary.histogram.iter().map(|(face, n)| *face as u64 * *n as u64).sum::<u64>());
+        assert_eq!(summary.mean, Some(summary.sum as f64 / rolls as f64));
+        assert_eq!((summary.min, summary.max), (Some(1), Some(20)));
+
+        let empty = simulate_parallel_seeded(20, 0, 42);
+        assert_eq!((empty.count, empty.mean, empty.min), (0, None, None));
+    }
+}
@@ -0,0 +1,333 @@
+/*!
+Dice notation parsing.
+
+Supports the classic `NdS+M` / `NdS-M` grammar, where `N` is the number of dice (defaults to 1 when omitted), `S` is
+the number of sides and `M` is an optional flat modifier, e.g. `d20`, `2d6`, `3d8 + 2` or `1d4-1`.
+*/
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+#[cfg(feature = "std")]
+use crate::DieStdRng;
+use crate::{Die, DieBuilder, DieRng};
+
+/// Errors produced while parsing dice notation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The notation was empty or only whitespace.
+    Empty,
+    /// The notation did not contain the `d` separating count and sides.
+    MissingDie,
+    /// The dice count was not a positive integer.
+    InvalidCount(String),
+    /// No side count followed the `d`.
+    MissingSides,
+    /// The side count was not an integer of at least 2.
+    InvalidSides(String),
+    /// The modifier following `+`/`-` was not an integer.
+    InvalidModifier(String),
+    /// Unexpected characters were found after the expression.
+    TrailingInput(String),
+    /// The expression could total more than fits in an `i32`.
+    TooLarge,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "dice notation is empty"),
+            ParseError::MissingDie => write!(f, "dice notation is missing the 'd' separator"),
+            ParseError::InvalidCount(s) => write!(f, "invalid dice count '{}'", s),
+            ParseError::MissingSides => write!(f, "dice notation is missing the number of sides"),
+            ParseError::InvalidSides(s) => write!(f, "invalid number of sides '{}', must be at least 2", s),
+            ParseError::InvalidModifier(s) => write!(f, "invalid modifier '{}'", s),
+            ParseError::TrailingInput(s) => write!(f, "unexpected input '{}'", s),
+            ParseError::TooLarge => write!(f, "dice expression can total more than {}", i32::MAX),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// A parsed dice expression, i.e. `count` dice of `sides` sides plus a flat `modifier`.
+pub struct RollExpr {
+    count: u32,
+    sides: u16,
+    modifier: i32,
+    die: Die,
+}
+
+impl RollExpr {
+    /// Replace the RNG used by the expression's underlying Die.
+    pub fn rng(mut self, rng: Box<dyn DieRng>) -> RollExpr {
+        self.die = DieBuilder::with_rng(rng).sides(self.sides).build();
+        self
+    }
+
+    /// The number of dice rolled.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// The number of sides on each die.
+    pub fn sides(&self) -> u16 {
+        self.sides
+    }
+
+    /// The flat modifier applied to the sum.
+    pub fn modifier(&self) -> i32 {
+        self.modifier
+    }
+
+    /// Rolls every die in the expression, sums the results and applies the modifier.
+    pub fn roll(&mut self) -> i32 {
+        let mut total = self.modifier;
+        for _i in 0..self.count {
+            total += self.die.roll() as i32;
+        }
+        total
+    }
+}
+
+/// Builds a `RollExpr` from dice notation written directly in code, analogous to `vec!`.
+///
+/// Supports the same `dS`, `NdS` and `NdS + M` forms as `parse`. The expression always uses the default RNG, users
+/// needing a custom RNG should use `parse_with_rng` or `RollExpr::rng`. The notation is checked at compile time, so
+/// malformed notation fails to compile:
+///
+/// ```compile_fail
+/// let expr = game_die::die!(2d);
+/// ```
+///
+/// ```compile_fail
+/// let expr = game_die::die!(d0);
+/// ```
+///
+/// ```
+/// use game_die::die;
+///
+/// let mut d20 = die!(d20);
+/// assert!((1..=20).contains(&d20.roll()));
+///
+/// let mut expr = die!(3d8 + 2);
+/// assert_eq!((expr.count(), expr.sides(), expr.modifier()), (3, 8, 2));
+/// assert!((5..=26).contains(&expr.roll()));
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! die {
+    ($($notation:tt)+) => {{
+        const { assert!($crate::parse::is_valid_notation(stringify!($($notation)+)), "invalid dice notation") };
+        $crate::parse::parse(stringify!($($notation)+)).expect("invalid dice notation")
+    }};
+}
+
+/// Skips ASCII whitespace in `bytes` from `i`, returning the index of the next other byte.
+const fn skip_whitespace(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+/// Reads the decimal number at `i` in `bytes`, returning it (saturated well above `u32::MAX`) along with the index
+/// following its last digit.
+const fn read_number(bytes: &[u8], mut i: usize) -> (u64, usize) {
+    let mut value: u64 = 0;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        if value < 1 << 40 {
+            value = value * 10 + (bytes[i] - b'0') as u64;
+        }
+        i += 1;
+    }
+    (value, i)
+}
+
+#[doc(hidden)]
+/// Whether `parse` would accept `notation` (for ASCII input), usable in constant evaluation so `die!` can reject
+/// malformed notation at compile time.
+pub const fn is_valid_notation(notation: &str) -> bool {
+    let bytes = notation.as_bytes();
+    let mut i = skip_whitespace(bytes, 0);
+    let (count, end) = read_number(bytes, i);
+    let count = if end == i { 1 } else { count };
+    i = skip_whitespace(bytes, end);
+    if count == 0 || count > u32::MAX as u64 || i >= bytes.len() || (bytes[i] != b'd' && bytes[i] != b'D') {
+        return false;
+    }
+    i = skip_whitespace(bytes, i + 1);
+    let (sides, end) = read_number(bytes, i);
+    if end == i || sides < 2 || sides > u16::MAX as u64 {
+        return false;
+    }
+    i = skip_whitespace(bytes, end);
+    let mut modifier: i64 = 0;
+    if i < bytes.len() {
+        let sign = match bytes[i] {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return false,
+        };
+        i = skip_whitespace(bytes, i + 1);
+        let (value, end) = read_number(bytes, i);
+        if end == i || value > i32::MAX as u64 || skip_whitespace(bytes, end) != bytes.len() {
+            return false;
+        }
+        modifier = sign * value as i64;
+    }
+    count as i64 * sides as i64 + modifier <= i32::MAX as i64
+}
+
+#[cfg(feature = "std")]
+/// Parses dice notation such as `2d6+3` into a rollable expression using the default RNG (only if feature "std" is
+/// enabled).
+pub fn parse(notation: &str) -> Result<RollExpr, ParseError> {
+    parse_with_rng(notation, Box::new(DieStdRng::new()))
+}
+
+/// Parses dice notation such as `2d6+3` into a rollable expression using the given RNG.
+pub fn parse_with_rng(notation: &str, rng: Box<dyn DieRng>) -> Result<RollExpr, ParseError> {
+    let notation = notation.trim();
+    if notation.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    let (count, rest) = notation.split_once(['d', 'D']).ok_or(ParseError::MissingDie)?;
+
+    let count = count.trim();
+    let count = if count.is_empty() {
+        1
+    } else {
+        match count.parse::<u32>() {
+            Ok(c) if c > 0 && count.bytes().all(|b| b.is_ascii_digit()) => c,
+            _ => return Err(ParseError::InvalidCount(count.to_string())),
+        }
+    };
+
+    let rest = rest.trim_start();
+    let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    let (sides, rest) = rest.split_at(digits);
+    if sides.is_empty() {
+        return match rest.trim() {
+            "" => Err(ParseError::MissingSides),
+            other => Err(ParseError::InvalidSides(other.to_string())),
+        };
+    }
+    let sides = match sides.parse::<u16>() {
+        Ok(s) if s > 1 => s,
+        _ => return Err(ParseError::InvalidSides(sides.to_string())),
+    };
+
+    let rest = rest.trim();
+    let modifier = if rest.is_empty() {
+        0
+    } else {
+        let (sign, value) = if let Some(value) = rest.strip_prefix('+') {
+            (1, value.trim())
+        } else if let Some(value) = rest.strip_prefix('-') {
+            (-1, value.trim())
+        } else {
+            return Err(ParseError::TrailingInput(rest.to_string()));
+        };
+        match value.parse::<i32>() {
+            Ok(m) if value.bytes().all(|b| b.is_ascii_digit()) => sign * m,
+            _ => return Err(ParseError::InvalidModifier(value.to_string())),
+        }
+    };
+    // The lowest total can't underflow since the modifier is at least -i32::MAX, but the highest can overflow.
+    if count as i64 * sides as i64 + modifier as i64 > i32::MAX as i64 {
+        return Err(ParseError::TooLarge);
+    }
+
+    Ok(RollExpr {
+        count,
+        sides,
+        modifier,
+        die: DieBuilder::with_rng(rng).sides(sides).build(),
+    })
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct _DieTerribleRng {}
+    impl DieRng for _DieTerribleRng {
+        fn random_int(&mut self, l: u16, _h: u16) -> u16 {
+            l
+        }
+    }
+
+    #[test]
+    fn parse_full_notation() {
+        let expr = parse("2d6+3").unwrap();
+        assert_eq!(expr.count(), 2);
+        assert_eq!(expr.sides(), 6);
+        assert_eq!(expr.modifier(), 3);
+    }
+
+    #[test]
+    fn parse_bare_die_and_whitespace() {
+        let expr = parse("d20").unwrap();
+        assert_eq!(expr.count(), 1);
+        assert_eq!(expr.sides(), 20);
+        assert_eq!(expr.modifier(), 0);
+
+        let expr = parse(" 3d8 - 2 ").unwrap();
+        assert_eq!(expr.count(), 3);
+        assert_eq!(expr.sides(), 8);
+        assert_eq!(expr.modifier(), -2);
+    }
+
+    #[test]
+    fn parse_malformed_notation() {
+        assert_eq!(parse("").err(), Some(ParseError::Empty));
+        assert_eq!(parse("26").err(), Some(ParseError::MissingDie));
+        assert_eq!(parse("2d").err(), Some(ParseError::MissingSides));
+        assert_eq!(parse("d0").err(), Some(ParseError::InvalidSides("0".to_string())));
+        assert_eq!(parse("xd6").err(), Some(ParseError::InvalidCount("x".to_string())));
+        assert_eq!(parse("2d6+").err(), Some(ParseError::InvalidModifier("".to_string())));
+        assert_eq!(parse("2d6*2").err(), Some(ParseError::TrailingInput("*2".to_string())));
+        assert_eq!(parse("2d6é").err(), Some(ParseError::TrailingInput("é".to_string())));
+        assert_eq!(parse("+2d6").err(), Some(ParseError::InvalidCount("+2".to_string())));
+        assert_eq!(parse("100000d65535").err(), Some(ParseError::TooLarge));
+        assert_eq!(parse("1d2+2147483646").err(), Some(ParseError::TooLarge));
+        assert_eq!(parse("1d2+2147483645").unwrap().rng(Box::new(_DieTerribleRng{})).roll(), 2147483646);
+    }
+
+    #[test]
+    fn roll_expression_with_custom_rng() {
+        let mut expr = parse("3d6+2").unwrap().rng(Box::new(_DieTerribleRng{}));
+        assert_eq!(expr.roll(), 5);
+        let mut expr = parse("2d4-3").unwrap().rng(Box::new(_DieTerribleRng{}));
+        assert_eq!(expr.roll(), -1);
+    }
+
+    #[test]
+    fn die_macro_builds_expression() {
+        let expr = crate::die!(2d6 - 1);
+        assert_eq!((expr.count(), expr.sides(), expr.modifier()), (2, 6, -1));
+        let expr = crate::die!(d12);
+        assert_eq!((expr.count(), expr.sides(), expr.modifier()), (1, 12, 0));
+    }
+
+    #[test]
+    fn notation_validity_matches_parse() {
+        let cases = [
+            "d20", "2d6+3", " 3d8 - 2 ", "1D4", "", "26", "2d", "d0", "d1", "xd6", "+2d6", "0d6", "2d6+", "2d6*2",
+            "2d6 + 1 2", "2 2d6", "100000d65535", "1d2+2147483645", "1d2+2147483646", "1d70000", "2d6+-1",
+        ];
+        for notation in cases {
+            assert_eq!(is_valid_notation(notation), parse(notation).is_ok(), "{:?}", notation);
+        }
+    }
+
+    #[test]
+    fn roll_expression_in_range() {
+        let mut expr = parse("2d6+3").unwrap();
+        for _i in 0..100 {
+            assert!((5..=15).contains(&expr.roll()));
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use alloc::boxed::Box;
+#[cfg(feature = "history")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::DieStdRng;
+use crate::{Die, DieBuilder, DieRng};
+
+/// Percentile Die
+///
+/// Rolls 1-100 using a tens d10 and a units d10, as with a classic percentile roll. A d10 showing 10 is read as 0, so
+/// each roll is `tens * 10 + units` with a double zero read as 100.
+pub struct PercentileDie {
+    die: Die,
+}
+
+#[cfg(feature = "std")]
+impl Default for PercentileDie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PercentileDie {
+    #[cfg(feature = "std")]
+    /// Creates a new PercentileDie using a standard RNG (only if feature "std" is enabled).
+    pub fn new() -> PercentileDie {
+        Self::with_rng(Box::new(DieStdRng::new()))
+    }
+
+    /// Creates a new PercentileDie using the given RNG for both d10s.
+    pub fn with_rng(rng: Box<dyn DieRng>) -> PercentileDie {
+        Self {
+            die: DieBuilder::with_rng(rng).sides(10).build(),
+        }
+    }
+
+    /// Rolls the tens and units d10s, returning a value in `1..=100`.
+    pub fn roll(&mut self) -> u16 {
+        let tens = self.die.roll() % 10;
+        let units = self.die.roll() % 10;
+        match tens * 10 + units {
+            0 => 100,
+            value => value,
+        }
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the history of the individual d10 rolls, tens then units for each percentile roll (only if feature
+    /// "history" is enabled)
+    pub fn get_history(&self) -> Vec<u16> {
+        self.die.get_history()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SequenceRng;
+    use alloc::vec;
+
+    #[test]
+    fn roll_combines_tens_and_units() {
+        let rng = SequenceRng::new(vec![3, 7, 10, 10, 10, 5, 3, 10]);
+        let mut die = PercentileDie::with_rng(Box::new(rng));
+        assert_eq!(die.roll(), 37);
+        assert_eq!(die.roll(), 100);
+        assert_eq!(die.roll(), 5);
+        assert_eq!(die.roll(), 30);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_in_range() {
+        let mut die = PercentileDie::new();
+        for _i in 0..1000 {
+            assert!((1..=100).contains(&die.roll()));
+        }
+    }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn roll_records_both_d10s() {
+        let mut die = PercentileDie::with_rng(Box::new(SequenceRng::new(vec![4, 2])));
+        assert_eq!(die.roll(), 42);
+        assert_eq!(die.get_history(), vec![4, 2]);
+    }
+}
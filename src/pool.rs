@@ -0,0 +1,320 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::distribution::convolve;
+use crate::Die;
+
+/// Whether a Die in a DicePool adds to or subtracts from the pool's sum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sign {
+    /// The roll is added to the sum.
+    Plus,
+    /// The roll is subtracted from the sum.
+    Minus,
+}
+
+impl Sign {
+    /// Applies the sign to a roll.
+    fn apply(self, roll: u16) -> i32 {
+        match self {
+            Sign::Plus => roll as i32,
+            Sign::Minus => -(roll as i32),
+        }
+    }
+}
+
+/// Dice Pool
+///
+/// Holds several (potentially differently sided) Die objects which are rolled together, e.g. `1d20 + 2d6`. Dice can
+/// also be subtracted from the sum, e.g. `1d20 - 1d4`. Dice are always rolled in the order they were added to the pool.
+#[derive(Default)]
+pub struct DicePool {
+    dice: Vec<(Die, Sign)>,
+}
+
+impl DicePool {
+    /// Creates a new, empty DicePool.
+    pub fn new() -> DicePool {
+        Self { dice: Vec::new() }
+    }
+
+    /// Add a Die to the pool.
+    pub fn add(&mut self, die: Die) {
+        self.dice.push((die, Sign::Plus));
+    }
+
+    /// Add a Die whose roll is subtracted from the pool's sum, e.g. the `1d4` of `1d20 - 1d4`.
+    pub fn add_subtractive(&mut self, die: Die) {
+        self.dice.push((die, Sign::Minus));
+    }
+
+    /// Iterate over the dice contained in the pool, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = &Die> + '_ {
+        self.dice.iter().map(|(die, _)| die)
+    }
+
+    /// Iterate over the dice contained in the pool along with their signs, in insertion order.
+    pub fn terms(&self) -> impl Iterator<Item = (&Die, Sign)> + '_ {
+        self.dice.iter().map(|(die, sign)| (die, *sign))
+    }
+
+    /// Computes the pool's most likely sum from the dice's sides and weights, without rolling, e.g. 7 for `2d6`. Ties
+    /// resolve to the smaller sum. Like `roll_sum`, subtractive dice may make the sum negative. Returns `None` for an
+    /// empty pool.
+    pub fn most_likely_sum(&self) -> Option<i32> {
+        if self.dice.is_empty() {
+            return None;
+        }
+        // Equal probabilities can differ in the last bits depending on the order they were summed in.
+        self.sum_distribution()
+            .into_iter()
+            .fold(None, |best: Option<(i32, f64)>, (sum, p)| match best {
+                Some((_, best_p)) if p <= best_p + 1e-12 => best,
+                _ => Some((sum, p)),
+            })
+            .map(|(sum, _)| sum)
+    }
+
+    /// Computes the exact probability of the pool's sum (see `roll_sum`) being `target`, e.g. 1/216 for a 3 on `3d6`,
+    /// from the dice's sides and weights without rolling. Impossible sums give 0.0.
+    pub fn probability_of_sum(&self, target: i32) -> f64 {
+        self.sum_distribution().get(&target).copied().unwrap_or(0.0)
+    }
+
+    /// Computes the exact probability of the pool's sum being at least `target`, like `probability_of_sum`.
+    pub fn probability_of_sum_at_least(&self, target: i32) -> f64 {
+        self.sum_distribution().range(target..).map(|(_, p)| p).sum()
+    }
+
+    /// The probability of every possible sum of the pool. An empty pool always sums to 0.
+    fn sum_distribution(&self) -> BTreeMap<i32, f64> {
+        self.dice.iter().fold(BTreeMap::from([(0, 1.0)]), |distribution, (die, sign)| {
+            convolve(&distribution, die, *sign == Sign::Minus)
+        })
+    }
+
+    /// Rolls every Die in the pool, returning each result in insertion order. Results are never negated, even for
+    /// subtractive dice.
+    pub fn roll_all(&mut self) -> Vec<u16> {
+        self.dice.iter_mut().map(|(die, _)| die.roll()).collect()
+    }
+
+    /// Rolls every Die in the pool, returning the sum of the results with subtractive dice subtracted. The sum may be
+    /// negative, see `roll_sum_non_negative` for systems which don't allow that.
+    pub fn roll_sum(&mut self) -> i32 {
+        self.roll_sum_detailed().0
+    }
+
+    /// Rolls every Die in the pool like `roll_sum`, but clamps a negative sum to 0.
+    pub fn roll_sum_non_negative(&mut self) -> u32 {
+        self.roll_sum().max(0) as u32
+    }
+
+    /// Rolls every Die in the pool, returning the sum (see `roll_sum`) along with each result in insertion order.
+    pub fn roll_sum_detailed(&mut self) -> (i32, Vec<u16>) {
+        let rolls = self.roll_all();
+        let sum = rolls.iter().zip(&self.dice).map(|(roll, (_, sign))| sign.apply(*roll)).sum();
+        (sum, rolls)
+    }
+
+    /// Rolls every Die in the pool, returning a human readable summary with the pool's notation, each result in
+    /// insertion order and the sum, e.g. `1d20 + 2d6 - 1d4: [14, 2, 5, 3] = 18`. Dice are grouped by their number of
+    /// sides and sign in the order they first appear. An empty pool gives `[] = 0`.
+    pub fn roll_formatted(&mut self) -> String {
+        let mut groups: Vec<(u16, Sign, usize)> = Vec::new();
+        for (die, sign) in &self.dice {
+            match groups.iter_mut().find(|(sides, s, _)| *sides == die.sides() && s == sign) {
+                Some((_, _, count)) => *count += 1,
+                None => groups.push((die.sides(), *sign, 1)),
+            }
+        }
+        let (sum, rolls) = self.roll_sum_detailed();
+        let mut summary = String::new();
+        for (i, (sides, sign, count)) in groups.iter().enumerate() {
+            let separator = match (i, sign) {
+                (0, Sign::Plus) => "",
+                (0, Sign::Minus) => "-",
+                (_, Sign::Plus) => " + ",
+                (_, Sign::Minus) => " - ",
+            };
+            write!(summary, "{}{}d{}", separator, count, sides).expect("writing to a String cannot fail");
+        }
+        if !summary.is_empty() {
+            summary.push_str(": ");
+        }
+        write!(summary, "{:?} = {}", rolls, sum).expect("writing to a String cannot fail");
+        summary
+    }
+
+    /// Rolls every Die in the pool, returning how many results met or exceeded `target`.
+    pub fn count_successes(&mut self, target: u16) -> usize {
+        self.roll_all().into_iter().filter(|roll| *roll >= target).count()
+    }
+
+    /// Rolls every Die in the pool, counting results which met or exceeded `target` as successes and subtracting one
+    /// for every 1 rolled (a botch). The tally is negative when there are more botches than successes.
+    pub fn count_successes_with_botch(&mut self, target: u16) -> i32 {
+        self.roll_all().into_iter().fold(0, |tally, roll| {
+            if roll == 1 {
+                tally - 1
+            } else if roll >= target {
+                tally + 1
+            } else {
+                tally
+            }
+        })
+    }
+}
+
+/// Rolls every Die yielded by `dice` once and returns the sum of the results, for dice kept in the caller's own
+/// container rather than a DicePool. Each roll is recorded by the Die that made it.
+pub fn roll_all<'a, I: IntoIterator<Item = &'a mut Die>>(dice: I) -> u32 {
+    dice.into_iter().map(|die| die.roll() as u32).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DieBuilder, DieRng, SequenceRng};
+    use alloc::boxed::Box;
+    use alloc::vec;
+
+    #[derive(Clone)]
+    struct _DieMaxRng {}
+    impl DieRng for _DieMaxRng {
+        fn random_int(&mut self, _l: u16, h: u16) -> u16 {
+            h
+        }
+    }
+
+    fn d(sides: u16) -> Die {
+        DieBuilder::with_rng(Box::new(_DieMaxRng{})).sides(sides).build()
+    }
+
+    fn fixed(sides: u16, value: u16) -> Die {
+        DieBuilder::with_rng(Box::new(SequenceRng::new(vec![value]))).sides(sides).build()
+    }
+
+    #[test]
+    fn roll_mixed_pool() {
+        let mut pool = DicePool::new();
+        pool.add(d(20));
+        pool.add(d(6));
+        pool.add(d(6));
+        assert_eq!(pool.iter().count(), 3);
+        let rolls = pool.roll_all();
+        assert_eq!(rolls, vec![20, 6, 6]);
+        assert_eq!(pool.roll_sum(), rolls.iter().map(|r| *r as i32).sum::<i32>());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn roll_sum_with_breakdown() {
+        let mut pool = DicePool::new();
+        pool.add(fixed(20, 14));
+        pool.add(fixed(6, 2));
+        pool.add(fixed(4, 3));
+        let (sum, rolls) = pool.roll_sum_detailed();
+        assert_eq!(rolls, vec![14, 2, 3]);
+        assert_eq!(sum, 19);
+
+        let mut pool = DicePool::new();
+        pool.add(Die::builder().sides(20).build());
+        pool.add(Die::builder().sides(6).build());
+        let (sum, rolls) = pool.roll_sum_detailed();
+        assert_eq!(sum, rolls.iter().map(|r| *r as i32).sum::<i32>());
+    }
+
+    #[test]
+    fn roll_all_sums_any_container() {
+        let mut dice = vec![fixed(20, 14), fixed(6, 2), fixed(4, 3)];
+        assert_eq!(roll_all(&mut dice), 19);
+        assert_eq!(roll_all(dice.iter_mut().take(2)), 16);
+        assert_eq!(roll_all(&mut dice[2..]), 3);
+        assert_eq!(roll_all(Vec::new()), 0);
+        #[cfg(feature = "history")]
+        assert_eq!(dice.iter().map(|die| die.get_history().len()).collect::<Vec<_>>(), vec![2, 2, 2]);
+    }
+
+    #[test]
+    fn most_likely_sum() {
+        let mut pool = DicePool::new();
+        assert_eq!(pool.most_likely_sum(), None);
+        pool.add(d(6));
+        assert_eq!(pool.most_likely_sum(), Some(1));
+        pool.add(d(6));
+        assert_eq!(pool.most_likely_sum(), Some(7));
+        pool.add_subtractive(d(8));
+        assert_eq!(pool.most_likely_sum(), Some(2));
+    }
+
+    #[test]
+    fn probability_of_sum() {
+        let mut pool = DicePool::new();
+        assert_eq!(pool.probability_of_sum(0), 1.0);
+        for _i in 0..3 {
+            pool.add(d(6));
+        }
+        assert!((pool.probability_of_sum(3) - 1.0 / 216.0).abs() < 1e-12);
+        assert!((pool.probability_of_sum(10) - 27.0 / 216.0).abs() < 1e-12);
+        assert!((pool.probability_of_sum_at_least(17) - 4.0 / 216.0).abs() < 1e-12);
+        assert!((pool.probability_of_sum_at_least(3) - 1.0).abs() < 1e-12);
+        assert_eq!((pool.probability_of_sum(2), pool.probability_of_sum(19)), (0.0, 0.0));
+        assert_eq!(pool.probability_of_sum_at_least(19), 0.0);
+
+        pool.add_subtractive(d(4));
+        assert!((pool.probability_of_sum(-1) - 1.0 / 864.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn subtractive_dice() {
+        let mut pool = DicePool::new();
+        pool.add(fixed(20, 12));
+        pool.add_subtractive(fixed(4, 3));
+        assert_eq!(pool.roll_sum(), 9);
+        assert_eq!(pool.roll_sum_detailed(), (9, vec![12, 3]));
+        assert_eq!(pool.terms().map(|(_, sign)| sign).collect::<Vec<_>>(), vec![Sign::Plus, Sign::Minus]);
+        assert_eq!(pool.roll_formatted(), "1d20 - 1d4: [12, 3] = 9");
+
+        let mut pool = DicePool::new();
+        pool.add_subtractive(fixed(6, 5));
+        pool.add(fixed(4, 1));
+        assert_eq!(pool.roll_sum(), -4);
+        assert_eq!(pool.roll_sum_non_negative(), 0);
+        assert_eq!(pool.roll_formatted(), "-1d6 + 1d4: [5, 1] = -4");
+    }
+
+    #[test]
+    fn roll_formatted_includes_breakdown() {
+        let mut pool = DicePool::new();
+        for value in [4, 2, 5] {
+            pool.add(fixed(6, value));
+        }
+        assert_eq!(pool.roll_formatted(), "3d6: [4, 2, 5] = 11");
+        pool.add(fixed(20, 14));
+        pool.add(fixed(6, 1));
+        assert_eq!(pool.roll_formatted(), "4d6 + 1d20: [4, 2, 5, 14, 1] = 26");
+        assert_eq!(DicePool::new().roll_formatted(), "[] = 0");
+    }
+
+    #[test]
+    fn count_successes_in_pool() {
+        let mut pool = DicePool::new();
+        for value in [1, 3, 6, 8, 10, 1, 9] {
+            pool.add(fixed(10, value));
+        }
+        pool.add(fixed(6, 6));
+        assert_eq!(pool.count_successes(6), 5);
+        assert_eq!(pool.count_successes_with_botch(6), 3);
+        assert_eq!(pool.count_successes_with_botch(11), -2);
+    }
+
+    #[test]
+    fn roll_empty_pool() {
+        let mut pool = DicePool::new();
+        assert!(pool.roll_all().is_empty());
+        assert_eq!(pool.roll_sum(), 0);
+    }
+}
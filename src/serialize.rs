@@ -0,0 +1,202 @@
+//! Serde support for the Die (only if feature "serde" is enabled).
+//!
+//! Only the Die's configuration (sides, weights and faces), history and face counts are serialized.
+//! `Box<dyn DieRng>` cannot be serialized, so a deserialized Die is always reconstructed with the default
+//! `DieStdRng`. The timestamps of the timed history (if feature "timed_history" is enabled) can't be serialized
+//! either, so a deserialized Die starts with an empty timed history. Invalid configuration fails deserialization with
+//! the error `DieBuilder::try_build` would return, rather than falling back to defaults.
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Die, DEFAULT_MAX_EXPLOSIONS};
+
+#[derive(Serialize, Deserialize)]
+struct DieState {
+    sides: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    weights: Option<Vec<f64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    faces: Option<Vec<u16>>,
+    #[serde(default = "default_max_explosions")]
+    max_explosions: u32,
+    #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+    zero_indexed: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    crit_low: Option<u16>,
+    #[serde(default)]
+    total_rolls: u64,
+    #[cfg(feature = "history")]
+    #[serde(default)]
+    history: Vec<u16>,
+    #[cfg(feature = "history")]
+    #[serde(default)]
+    history_capacity: usize,
+    #[cfg(feature = "summary_history")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    face_counts: Option<Vec<u64>>,
+}
+
+fn default_max_explosions() -> u32 {
+    DEFAULT_MAX_EXPLOSIONS
+}
+
+impl Serialize for Die {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DieState {
+            sides: self.sides,
+            weights: self.weights.as_ref().map(|w| w.values.clone()),
+            faces: self.faces.clone(),
+            max_explosions: self.max_explosions,
+            zero_indexed: self.zero_indexed,
+            crit_low: Some(self.crit_low).filter(|low| *low != self.max()),
+            total_rolls: self.total_rolls,
+            #[cfg(feature = "history")]
+            history: self.history().to_vec(),
+            #[cfg(feature = "history")]
+            history_capacity: self.history_capacity,
+            #[cfg(feature = "summary_history")]
+            face_counts: Some(self.face_counts.clone()),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Die {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = DieState::deserialize(deserializer)?;
+        let mut builder = Die::builder()
+            .sides(state.sides)
+            .max_explosions(state.max_explosions)
+            .zero_indexed(state.zero_indexed);
+        if let Some(weights) = state.weights {
+            builder = builder.weights(weights);
+        }
+        if let Some(faces) = state.faces {
+            builder = builder.faces(faces);
+        }
+        if let Some(low) = state.crit_low {
+            builder = builder.crit_range(low);
+        }
+        #[cfg(feature = "history")]
+        {
+            builder = builder.history_capacity(state.history_capacity);
+        }
+        let mut die = builder.try_build().map_err(serde::de::Error::custom)?;
+        die.total_rolls = state.total_rolls;
+        // The saved history came from this Die, which may have recorded values outside of its faces through
+        // `roll_range` or a roll filter, so unlike `set_history` it is restored without checking the faces.
+        #[cfg(feature = "history")]
+        {
+            let mut history = state.history;
+            if die.history_capacity > 0 && history.len() > die.history_capacity {
+                history.drain(..history.len() - die.history_capacity);
+            }
+            die.history.replace(history);
+        }
+        // As with `set_history`, data without face counts leaves them at zero rather than counting the history.
+        #[cfg(feature = "summary_history")]
+        if let Some(counts) = state.face_counts.filter(|counts| counts.len() == die.face_counts.len()) {
+            die.face_counts = counts;
+        }
+        Ok(die)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn round_trip_keeps_sides() {
+        let die = DieBuilder::new().sides(20).build();
+        let json = serde_json::to_string(&die).unwrap();
+        let mut restored: Die = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.sides, 20);
+        assert!((1..=20).contains(&restored.roll()));
+    }
+
+    #[test]
+    fn round_trip_keeps_weights() {
+        let die = DieBuilder::new().weights(vec![1.0, 0.0, 0.0]).build();
+        let json = serde_json::to_string(&die).unwrap();
+        let mut restored: Die = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.sides, 3);
+        assert_eq!(restored.roll_many(20), vec![1; 20]);
+    }
+
+    #[test]
+    fn round_trip_keeps_zero_indexing() {
+        let die = DieBuilder::new().sides(4).zero_indexed(true).build();
+        let json = serde_json::to_string(&die).unwrap();
+        let restored: Die = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.range(), 0..=3);
+        assert!(!serde_json::to_string(&DieBuilder::new().build()).unwrap().contains("zero_indexed"));
+    }
+
+    #[test]
+    fn round_trip_keeps_faces() {
+        let die = DieBuilder::new().faces(vec![2, 4, 6, 8]).build();
+        let mut restored: Die = serde_json::from_str(&serde_json::to_string(&die).unwrap()).unwrap();
+        assert_eq!(restored.faces(), vec![2, 4, 6, 8]);
+        assert!([2, 4, 6, 8].contains(&restored.roll()));
+        assert!(!serde_json::to_string(&DieBuilder::new().build()).unwrap().contains("faces"));
+    }
+
+    #[test]
+    fn invalid_config_is_rejected() {
+        let error = serde_json::from_str::<Die>(r#"{"sides":1}"#).unwrap_err();
+        assert!(error.to_string().contains(&DieError::InvalidSides.to_string()));
+        let error = serde_json::from_str::<Die>(r#"{"sides":2,"weights":[-1.0,2.0]}"#).unwrap_err();
+        assert!(error.to_string().contains(&DieError::InvalidWeights.to_string()));
+    }
+
+    #[test]
+    fn round_trip_keeps_crit_range() {
+        let die = DieBuilder::new().sides(20).crit_range(18).build();
+        let restored: Die = serde_json::from_str(&serde_json::to_string(&die).unwrap()).unwrap();
+        assert_eq!(restored.crit_range(), 18..=20);
+    }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn round_trip_keeps_history() {
+        let mut die = DieBuilder::new().sides(12).build();
+        die.roll_many(4);
+        let json = serde_json::to_string(&die).unwrap();
+        let restored: Die = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.sides, 12);
+        assert_eq!(restored.get_history(), die.get_history());
+    }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn round_trip_keeps_out_of_range_history() {
+        let mut die = DieBuilder::new().sides(6).build();
+        die.roll_range(50, 60);
+        let restored: Die = serde_json::from_str(&serde_json::to_string(&die).unwrap()).unwrap();
+        assert_eq!(restored.get_history(), die.get_history());
+        assert!((50..=60).contains(&restored.get_history()[0]));
+    }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn history_is_trimmed_to_capacity() {
+        let restored: Die = serde_json::from_str(r#"{"sides":6,"history":[1,2,3],"history_capacity":2}"#).unwrap();
+        assert_eq!(restored.get_history(), vec![2, 3]);
+    }
+
+    #[cfg(feature = "summary_history")]
+    #[test]
+    fn round_trip_keeps_face_counts() {
+        let mut die = DieBuilder::new().sides(4).seed(1).build();
+        die.roll_many(50);
+        let restored: Die = serde_json::from_str(&serde_json::to_string(&die).unwrap()).unwrap();
+        assert_eq!(restored.face_counts(), die.face_counts());
+        assert_eq!(restored.face_counts().iter().sum::<u64>(), 50);
+
+        #[cfg(feature = "history")]
+        {
+            let restored: Die = serde_json::from_str(r#"{"sides":4,"history":[1,4,4]}"#).unwrap();
+            assert_eq!(restored.face_counts(), vec![0; 4]);
+        }
+    }
+}
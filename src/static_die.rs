@@ -0,0 +1,84 @@
+#[cfg(feature = "history")]
+use alloc::vec::Vec;
+
+use crate::{random_inclusive, DieRng, DEFAULT_SIDES};
+
+/// Statically dispatched Die
+///
+/// A uniform Die which stores its RNG by value instead of as a `Box<dyn DieRng>`. This avoids the heap allocation and
+/// the virtual call on every roll, letting the compiler inline the RNG in tight simulation loops. Use the regular Die
+/// when the RNG is only known at runtime or weights, callbacks and the other builder options are needed.
+#[derive(Clone)]
+pub struct StaticDie<R: DieRng> {
+    sides: u16,
+    rng: R,
+    total_rolls: u64,
+    #[cfg(feature = "history")]
+    history: Vec<u16>,
+}
+
+impl<R: DieRng> StaticDie<R> {
+    /// Creates a new StaticDie with the given number of sides and RNG. The default number of sides is used if a value
+    /// below 2 is passed.
+    pub fn with_rng(sides: u16, rng: R) -> StaticDie<R> {
+        Self {
+            sides: if sides > 1 { sides } else { DEFAULT_SIDES },
+            rng,
+            total_rolls: 0,
+            #[cfg(feature = "history")]
+            history: Vec::new(),
+        }
+    }
+
+    /// Gets the number of sides of the Die.
+    pub fn sides(&self) -> u16 {
+        self.sides
+    }
+
+    /// Rolls the Die using it's RNG. The roll is recorded like a roll of the regular Die.
+    pub fn roll(&mut self) -> u16 {
+        let ret = random_inclusive(&mut self.rng, 1, self.sides);
+        self.total_rolls += 1;
+        #[cfg(feature = "history")]
+        self.history.push(ret);
+        ret
+    }
+
+    /// Gets the total number of times the Die has been rolled.
+    pub fn total_rolls(&self) -> u64 {
+        self.total_rolls
+    }
+
+    #[cfg(feature = "history")]
+    /// Gets the roll history list in chronological order (only if feature "history" is enabled)
+    pub fn get_history(&self) -> Vec<u16> {
+        self.history.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SequenceRng;
+    use alloc::vec;
+
+    #[test]
+    fn roll_uses_rng_by_value() {
+        let mut die = StaticDie::with_rng(6, SequenceRng::new(vec![4, 9, 2]));
+        assert_eq!(die.sides(), 6);
+        assert_eq!(die.roll(), 4);
+        assert_eq!(die.roll(), 6);
+        assert_eq!(die.roll(), 2);
+        assert_eq!(die.total_rolls(), 3);
+        assert_eq!(StaticDie::with_rng(0, SequenceRng::new(vec![])).sides(), DEFAULT_SIDES);
+    }
+
+    #[cfg(feature = "history")]
+    #[test]
+    fn rolls_are_recorded() {
+        let mut die = StaticDie::with_rng(6, SequenceRng::new(vec![3, 1]));
+        die.roll();
+        die.roll();
+        assert_eq!(die.get_history(), vec![3, 1]);
+    }
+}
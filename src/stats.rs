@@ -0,0 +1,92 @@
+/// Running Statistics (only if feature "stats" is enabled)
+///
+/// Accumulates the count, mean and variance of a stream of values in O(1) memory using Welford's algorithm, so
+/// statistics can be kept over billions of rolls without storing them.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningStats {
+    /// Creates a new, empty accumulator.
+    pub fn new() -> RunningStats {
+        Self::default()
+    }
+
+    /// Adds a value to the accumulator.
+    pub fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Removes a previously added value from the accumulator, reversing `push`. Removing a value which was never added
+    /// leaves meaningless statistics.
+    pub fn remove(&mut self, value: f64) {
+        if self.count <= 1 {
+            *self = Self::default();
+            return;
+        }
+        let mean = (self.mean * self.count as f64 - value) / (self.count - 1) as f64;
+        self.m2 -= (value - self.mean) * (value - mean);
+        self.mean = mean;
+        self.count -= 1;
+    }
+
+    /// The number of values accumulated.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The mean of the accumulated values, or `None` if there are none.
+    pub fn mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(self.mean)
+    }
+
+    /// The sample variance (N-1 denominator) of the accumulated values, or `None` if there are fewer than two.
+    pub fn variance(&self) -> Option<f64> {
+        if self.count < 2 {
+            return None;
+        }
+        Some(self.m2 / (self.count - 1) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_stats_match_direct_computation() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let mut stats = RunningStats::new();
+        assert_eq!(stats.mean(), None);
+        for value in values {
+            stats.push(value);
+        }
+        assert_eq!(stats.count(), 8);
+        assert!((stats.mean().unwrap() - 5.0).abs() < 1e-12);
+        assert!((stats.variance().unwrap() - 32.0 / 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn remove_reverses_push() {
+        let mut stats = RunningStats::new();
+        for value in [2.0, 4.0, 9.0] {
+            stats.push(value);
+        }
+        stats.remove(9.0);
+        assert_eq!(stats.count(), 2);
+        assert!((stats.mean().unwrap() - 3.0).abs() < 1e-12);
+        assert!((stats.variance().unwrap() - 2.0).abs() < 1e-12);
+        stats.remove(4.0);
+        stats.remove(2.0);
+        assert_eq!(stats, RunningStats::new());
+    }
+}